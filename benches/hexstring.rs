@@ -0,0 +1,82 @@
+use criterion::{
+  black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use hexstring::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+// From 16 B to 16 MiB, by power of 16.
+const BYTE_LENS: [usize; 6] = [16, 1 << 8, 1 << 12, 1 << 16, 1 << 20, 1 << 24];
+
+fn bytes(len: usize) -> Vec<u8> {
+  (0..len).map(|i| (i * 31 + 7) as u8).collect()
+}
+
+fn validation(c: &mut Criterion) {
+  let mut group = c.benchmark_group("validation");
+
+  // the owned strings are built outside of the measurement, which only covers the validation.
+  for len in BYTE_LENS {
+    let lowercase_s = LowerHexString::from(bytes(len)).to_string();
+    let uppercase_s = UpperHexString::from(bytes(len)).to_string();
+
+    group.throughput(Throughput::Bytes(lowercase_s.len() as u64));
+    group.bench_with_input(BenchmarkId::new("lower", len), &lowercase_s, |b, s| {
+      b.iter_batched(
+        || s.clone(),
+        |s| LowerHexString::new(black_box(s)).unwrap(),
+        BatchSize::LargeInput,
+      )
+    });
+    group.bench_with_input(BenchmarkId::new("upper", len), &uppercase_s, |b, s| {
+      b.iter_batched(
+        || s.clone(),
+        |s| UpperHexString::new(black_box(s)).unwrap(),
+        BatchSize::LargeInput,
+      )
+    });
+    group.bench_with_input(BenchmarkId::new("any", len), &uppercase_s, |b, s| {
+      b.iter_batched(
+        || s.clone(),
+        |s| AnyCaseHexString::new(black_box(s)).unwrap(),
+        BatchSize::LargeInput,
+      )
+    });
+  }
+
+  group.finish();
+}
+
+fn encode(c: &mut Criterion) {
+  let mut group = c.benchmark_group("encode");
+
+  for len in BYTE_LENS {
+    let bytes = bytes(len);
+
+    group.throughput(Throughput::Bytes(len as u64));
+    group.bench_with_input(BenchmarkId::new("lower", len), &bytes, |b, bytes| {
+      b.iter(|| LowerHexString::from(black_box(&bytes[..])))
+    });
+    group.bench_with_input(BenchmarkId::new("upper", len), &bytes, |b, bytes| {
+      b.iter(|| UpperHexString::from(black_box(&bytes[..])))
+    });
+  }
+
+  group.finish();
+}
+
+fn decode(c: &mut Criterion) {
+  let mut group = c.benchmark_group("decode");
+
+  for len in BYTE_LENS {
+    let hex = LowerHexString::from(bytes(len));
+
+    group.throughput(Throughput::Bytes(len as u64));
+    group.bench_with_input(BenchmarkId::new("lower", len), &hex, |b, hex| {
+      b.iter(|| black_box(hex).decode())
+    });
+  }
+
+  group.finish();
+}
+
+criterion_group!(benches, validation, encode, decode);
+criterion_main!(benches);
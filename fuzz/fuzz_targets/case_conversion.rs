@@ -0,0 +1,23 @@
+//! Converts arbitrary hexadecimal strings between cases, asserting they always remain valid.
+
+#![no_main]
+
+use hexstring::{AnyCaseHexString, Case, LowerHexString, UpperHexString};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|hex: AnyCaseHexString| {
+  assert!(AnyCaseHexString::new(hex.to_string()).is_ok());
+  assert!(Case::detect(&hex.to_string()).is_some());
+
+  let lowercase_hex: LowerHexString = hex.clone().to_case();
+  let uppercase_hex: UpperHexString = hex.clone().to_case();
+
+  assert!(LowerHexString::new(lowercase_hex.to_string()).is_ok());
+  assert!(UpperHexString::new(uppercase_hex.to_string()).is_ok());
+  assert!(lowercase_hex == uppercase_hex);
+  assert!(hex.eq_ignore_case(&lowercase_hex));
+  assert_eq!(
+    LowerHexString::from_mixed(hex.to_string()),
+    Ok(lowercase_hex)
+  );
+});
@@ -0,0 +1,61 @@
+//! Builds hexadecimal strings through the builder and the lossy constructor from arbitrary data,
+//! asserting they always result in valid hexadecimal strings.
+
+#![no_main]
+
+use std::io::Write;
+
+use hexstring::{LowerHexString, UpperHexString};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let mut builder = LowerHexString::builder();
+  let mut bytes = Vec::new();
+
+  // interprets the data as operations, the first byte of each chunk selecting the operation.
+  for chunk in data.chunks(9) {
+    let (op, args) = (chunk[0], &chunk[1..]);
+    let mut int = [0u8; 8];
+
+    int[..args.len()].copy_from_slice(args);
+
+    match op % 5 {
+      0 => {
+        builder.push_byte(op);
+        bytes.push(op);
+      }
+      1 => {
+        builder.push_bytes(args);
+        bytes.extend_from_slice(args);
+      }
+      2 => {
+        builder.push_u32_be(u32::from_be_bytes([int[0], int[1], int[2], int[3]]));
+        bytes.extend_from_slice(&int[..4]);
+      }
+      3 => {
+        builder.push_u64_le(u64::from_le_bytes(int));
+        bytes.extend_from_slice(&int);
+      }
+      _ => {
+        let n = builder.write(args).unwrap();
+
+        bytes.extend_from_slice(&args[..n]);
+      }
+    }
+  }
+
+  let hex = builder.build();
+
+  assert!(LowerHexString::new(hex.to_string()).is_ok());
+  assert_eq!(hex.decode(), bytes);
+
+  if let Ok(s) = std::str::from_utf8(data) {
+    if let Ok((hex, _)) = UpperHexString::from_lossy(s) {
+      assert!(UpperHexString::new(hex.to_string()).is_ok());
+    }
+
+    if let Ok((hex, _)) = LowerHexString::from_lossy(s) {
+      assert!(LowerHexString::new(hex.to_string()).is_ok());
+    }
+  }
+});
@@ -0,0 +1,29 @@
+//! Deserializes arbitrary JSON documents, asserting that only valid hexadecimal strings are
+//! accepted.
+
+#![no_main]
+
+use hexstring::{AnyCaseHexString, Lenient, LowerHexString, UpperHexString};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(hex) = serde_json::from_slice::<LowerHexString>(data) {
+    assert!(LowerHexString::new(hex.to_string()).is_ok());
+  }
+
+  if let Ok(Lenient(hex)) = serde_json::from_slice::<Lenient<LowerHexString>>(data) {
+    assert!(LowerHexString::new(hex.to_string()).is_ok());
+  }
+
+  if let Ok(hex) = serde_json::from_slice::<UpperHexString>(data) {
+    assert!(UpperHexString::new(hex.to_string()).is_ok());
+  }
+
+  if let Ok(hex) = serde_json::from_slice::<AnyCaseHexString>(data) {
+    assert!(AnyCaseHexString::new(hex.to_string()).is_ok());
+    assert_eq!(
+      serde_json::from_str::<AnyCaseHexString>(&serde_json::to_string(&hex).unwrap()).unwrap(),
+      hex
+    );
+  }
+});
@@ -0,0 +1,33 @@
+//! Round-trips arbitrary strings through `new`, the decoded bytes and `From<&[u8]>`.
+
+#![no_main]
+
+use hexstring::{AnyCaseHexString, LowerHexString, UpperHexString};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|s: String| {
+  if let Ok(hex) = LowerHexString::new(s.clone()) {
+    let bytes = hex.decode();
+
+    assert_eq!(LowerHexString::from(&bytes[..]), hex);
+  }
+
+  if let Ok(hex) = UpperHexString::new(s.clone()) {
+    let bytes = hex.decode();
+
+    assert_eq!(UpperHexString::from(&bytes[..]), hex);
+  }
+
+  match AnyCaseHexString::new(s.clone()) {
+    Ok(hex) => {
+      let bytes = hex.decode();
+
+      assert!(AnyCaseHexString::from(&bytes[..]).eq_ignore_case(&hex));
+      assert_eq!(hex.to_string(), s);
+    }
+    Err(_) => {
+      assert!(LowerHexString::new(s.clone()).is_err());
+      assert!(UpperHexString::new(s).is_err());
+    }
+  }
+});
@@ -0,0 +1,140 @@
+//! # hexstring-derive
+//!
+//! The `hexstring-derive` crate provides derive macros for the [`hexstring`][hexstring] crate.
+//!
+//! It should not be used directly but through the **derive** feature of the `hexstring` crate.
+//!
+//! [hexstring]: https://docs.rs/hexstring
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Index, Member};
+
+/// Derives the conversions, formatting and serde support of a newtype wrapping a hexadecimal value.
+///
+/// Refers to `hexstring::HexNewtype` for more details.
+#[proc_macro_derive(HexNewtype)]
+pub fn derive_hex_newtype(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  expand(input)
+    .unwrap_or_else(Error::into_compile_error)
+    .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+  let fields = match &input.data {
+    Data::Struct(data) => &data.fields,
+    _ => {
+      return Err(Error::new(
+        input.span(),
+        "HexNewtype can only be derived for structs",
+      ))
+    }
+  };
+
+  let field = match fields.iter().collect::<Vec<_>>()[..] {
+    [field] => field,
+    _ => {
+      return Err(Error::new(
+        fields.span(),
+        "HexNewtype can only be derived for structs with exactly one field",
+      ))
+    }
+  };
+
+  let member = match &field.ident {
+    Some(ident) => Member::Named(ident.clone()),
+    None => Member::Unnamed(Index::from(0)),
+  };
+  let constructor = match fields {
+    Fields::Named(_) => quote!(Self { #member: value }),
+    _ => quote!(Self(value)),
+  };
+
+  let name = &input.ident;
+  let inner = &field.ty;
+  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+  let predicates = where_clause.map(|where_clause| &where_clause.predicates);
+
+  let mut de_generics = input.generics.clone();
+
+  de_generics.params.insert(0, parse_quote!('de));
+
+  let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+  Ok(quote! {
+    impl #impl_generics ::std::fmt::Display for #name #ty_generics
+    where
+      #inner: ::std::fmt::Display,
+      #predicates
+    {
+      fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::std::fmt::Display::fmt(&self.#member, f)
+      }
+    }
+
+    impl #impl_generics ::std::str::FromStr for #name #ty_generics
+    where
+      #inner: ::std::str::FromStr,
+      #predicates
+    {
+      type Err = <#inner as ::std::str::FromStr>::Err;
+
+      fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+        <#inner as ::std::str::FromStr>::from_str(s).map(|value| #constructor)
+      }
+    }
+
+    impl #impl_generics ::std::convert::From<#inner> for #name #ty_generics #where_clause {
+      fn from(value: #inner) -> Self {
+        #constructor
+      }
+    }
+
+    impl #impl_generics ::std::convert::From<#name #ty_generics> for #inner #where_clause {
+      fn from(value: #name #ty_generics) -> Self {
+        value.#member
+      }
+    }
+
+    impl #impl_generics ::std::convert::AsRef<#inner> for #name #ty_generics #where_clause {
+      fn as_ref(&self) -> &#inner {
+        &self.#member
+      }
+    }
+
+    ::hexstring::__cfg_serde! {
+      impl #impl_generics ::hexstring::__private::serde::Serialize for #name #ty_generics
+      where
+        #inner: ::hexstring::__private::serde::Serialize,
+        #predicates
+      {
+        fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+        where
+          S: ::hexstring::__private::serde::Serializer,
+        {
+          ::hexstring::__private::serde::Serialize::serialize(&self.#member, serializer)
+        }
+      }
+
+      impl #de_impl_generics ::hexstring::__private::serde::Deserialize<'de> for #name #ty_generics
+      where
+        #inner: ::hexstring::__private::serde::Deserialize<'de>,
+        #predicates
+      {
+        fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+        where
+          D: ::hexstring::__private::serde::Deserializer<'de>,
+        {
+          <#inner as ::hexstring::__private::serde::Deserialize<'de>>::deserialize(deserializer)
+            .map(|value| #constructor)
+        }
+      }
+    }
+  })
+}
@@ -0,0 +1,247 @@
+//! Hexadecimal string of either case without const generics.
+
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{AnyCaseHexString, Case, Error, LowerHexString, UpperHexString};
+
+/// Hexadecimal string which is either lowercase or uppercase.
+///
+/// Unlike [`AnyCaseHexString`], it never mixes upper and lower characters and keeps track of its
+/// case at runtime, which allows heterogeneous collections to hold hexadecimal strings of both
+/// cases without generics.
+///
+/// A string without alphabetic characters is considered as lowercase.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{AnyHexString, Case, LowerHexString};
+///
+/// let hexes = ["deadbeef", "DEADBEEF"]
+///   .iter()
+///   .map(|s| s.parse::<AnyHexString>())
+///   .collect::<Result<Vec<_>, _>>()
+///   .unwrap();
+///
+/// assert_eq!(hexes[1].case(), Case::Upper);
+/// assert_eq!(hexes[1].clone().into_lower(), LowerHexString::new("deadbeef").unwrap());
+/// assert!("DeadBeef".parse::<AnyHexString>().is_err());
+/// ```
+#[cfg_attr(feature = "serde", derive(::serde::Serialize), serde(untagged))]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AnyHexString {
+  /// Lowercase hexadecimal string.
+  Lower(LowerHexString),
+  /// Uppercase hexadecimal string.
+  Upper(UpperHexString),
+}
+
+impl AnyHexString {
+  /// Constructs a new [`AnyHexString`] from a string, detecting its case from its first alphabetic
+  /// character.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal, or if it mixes upper and
+  /// lower characters.
+  pub fn new<S: Into<Cow<'static, str>>>(s: S) -> Result<Self, Error> {
+    let s = s.into();
+
+    match s.bytes().find(u8::is_ascii_alphabetic) {
+      Some(b) if b.is_ascii_uppercase() => UpperHexString::new(s).map(Self::Upper),
+      _ => LowerHexString::new(s).map(Self::Lower),
+    }
+  }
+
+  /// Returns the case of the [`AnyHexString`], either [`Case::Lower`] or [`Case::Upper`].
+  pub fn case(&self) -> Case {
+    match self {
+      Self::Lower(_) => Case::Lower,
+      Self::Upper(_) => Case::Upper,
+    }
+  }
+
+  /// Converts the [`AnyHexString`] into a [`LowerHexString`].
+  ///
+  /// This method performs a copy if the internal string is an uppercase string literal.
+  pub fn into_lower(self) -> LowerHexString {
+    match self {
+      Self::Lower(s) => s,
+      Self::Upper(s) => s.to_lowercase(),
+    }
+  }
+
+  /// Converts the [`AnyHexString`] into an [`UpperHexString`].
+  ///
+  /// This method performs a copy if the internal string is a lowercase string literal.
+  pub fn into_upper(self) -> UpperHexString {
+    match self {
+      Self::Lower(s) => s.to_uppercase(),
+      Self::Upper(s) => s,
+    }
+  }
+}
+
+impl Default for AnyHexString {
+  fn default() -> Self {
+    Self::Lower(LowerHexString::default())
+  }
+}
+
+impl fmt::Display for AnyHexString {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Lower(s) => fmt::Display::fmt(s, f),
+      Self::Upper(s) => fmt::Display::fmt(s, f),
+    }
+  }
+}
+
+impl FromStr for AnyHexString {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::new(s.to_owned())
+  }
+}
+
+impl From<LowerHexString> for AnyHexString {
+  fn from(s: LowerHexString) -> Self {
+    Self::Lower(s)
+  }
+}
+
+impl From<UpperHexString> for AnyHexString {
+  fn from(s: UpperHexString) -> Self {
+    Self::Upper(s)
+  }
+}
+
+impl From<AnyHexString> for LowerHexString {
+  fn from(s: AnyHexString) -> Self {
+    s.into_lower()
+  }
+}
+
+impl From<AnyHexString> for UpperHexString {
+  fn from(s: AnyHexString) -> Self {
+    s.into_upper()
+  }
+}
+
+impl From<AnyHexString> for AnyCaseHexString {
+  fn from(s: AnyHexString) -> Self {
+    match s {
+      AnyHexString::Lower(s) => s.to_case(),
+      AnyHexString::Upper(s) => s.to_case(),
+    }
+  }
+}
+
+impl TryFrom<AnyCaseHexString> for AnyHexString {
+  type Error = Error;
+
+  /// Converts the [`AnyCaseHexString`] if it does not mix upper and lower characters.
+  fn try_from(s: AnyCaseHexString) -> Result<Self, Self::Error> {
+    Self::new(s.into_cow())
+  }
+}
+
+impl From<&[u8]> for AnyHexString {
+  fn from(bytes: &[u8]) -> Self {
+    Self::Lower(LowerHexString::from(bytes))
+  }
+}
+
+impl From<Vec<u8>> for AnyHexString {
+  fn from(bytes: Vec<u8>) -> Self {
+    Self::from(&bytes[..])
+  }
+}
+
+impl<const N: usize> From<[u8; N]> for AnyHexString {
+  fn from(bytes: [u8; N]) -> Self {
+    Self::from(&bytes[..])
+  }
+}
+
+impl<const N: usize> TryFrom<AnyHexString> for [u8; N] {
+  type Error = Error;
+
+  fn try_from(s: AnyHexString) -> Result<Self, Self::Error> {
+    match s {
+      AnyHexString::Lower(s) => Self::try_from(s),
+      AnyHexString::Upper(s) => Self::try_from(s),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_constructs_from_str_of_either_case() {
+    assert_eq!(
+      AnyHexString::new("deadbeef"),
+      Ok(AnyHexString::Lower(
+        LowerHexString::new("deadbeef").unwrap()
+      ))
+    );
+    assert_eq!(
+      "0A0B".parse::<AnyHexString>(),
+      Ok(AnyHexString::Upper(UpperHexString::new("0A0B").unwrap()))
+    );
+    assert_eq!(AnyHexString::new("0123").unwrap().case(), Case::Lower);
+    assert_eq!(
+      AnyHexString::new("DeadBeef"),
+      Err(Error::InvalidCase {
+        expected: Case::Upper,
+        found: Case::Lower,
+        index: 1
+      })
+    );
+    assert_eq!(AnyHexString::new("abc"), Err(Error::OddLength));
+  }
+
+  #[test]
+  fn it_converts_between_cases() {
+    let hex = AnyHexString::from(UpperHexString::new("0A0B").unwrap());
+
+    assert_eq!(hex.to_string(), "0A0B");
+    assert_eq!(
+      hex.clone().into_lower(),
+      LowerHexString::new("0a0b").unwrap()
+    );
+    assert_eq!(
+      UpperHexString::from(hex.clone()),
+      UpperHexString::new("0A0B").unwrap()
+    );
+    assert_eq!(
+      AnyCaseHexString::from(hex),
+      AnyCaseHexString::new("0A0B").unwrap()
+    );
+    assert_eq!(
+      AnyHexString::try_from(AnyCaseHexString::new("0a0B").unwrap()),
+      Err(Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 3
+      })
+    );
+  }
+
+  #[test]
+  fn it_converts_from_and_into_bytes() {
+    let hex = AnyHexString::from([0xde, 0xad]);
+
+    assert_eq!(hex.case(), Case::Lower);
+    assert_eq!(<[u8; 2]>::try_from(hex), Ok([0xde, 0xad]));
+    assert_eq!(
+      <[u8; 2]>::try_from(AnyHexString::new("DEAD").unwrap()),
+      Ok([0xde, 0xad])
+    );
+  }
+}
@@ -0,0 +1,66 @@
+//! Fuzzing support through the `arbitrary` crate.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Case, HexString};
+
+impl<'a, const C: Case> Arbitrary<'a> for HexString<C> {
+  /// Generates a [`HexString`] from raw bytes, whose characters are of arbitrary case for
+  /// [`AnyCaseHexString`](crate::AnyCaseHexString).
+  fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+    let hex = Self::from(<&[u8]>::arbitrary(u)?);
+
+    match C {
+      Case::Any => {
+        let mut s = String::with_capacity(hex.0.len());
+
+        for c in hex.0.chars() {
+          if bool::arbitrary(u)? {
+            s.push(c.to_ascii_uppercase());
+          } else {
+            s.push(c);
+          }
+        }
+
+        Ok(unsafe { Self::new_unchecked(s) })
+      }
+      _ => Ok(hex),
+    }
+  }
+
+  fn size_hint(depth: usize) -> (usize, Option<usize>) {
+    <&[u8]>::size_hint(depth)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_generates_valid_hex_str() {
+    let data = (0..=255).cycle().take(4096).collect::<Vec<u8>>();
+    let mut u = Unstructured::new(&data);
+
+    while !u.is_empty() {
+      let lowercase_hex = LowerHexString::arbitrary(&mut u).unwrap();
+      let uppercase_hex = UpperHexString::arbitrary(&mut u).unwrap();
+      let any_case_hex = AnyCaseHexString::arbitrary(&mut u).unwrap();
+
+      assert!(LowerHexString::new(lowercase_hex.to_string()).is_ok());
+      assert!(UpperHexString::new(uppercase_hex.to_string()).is_ok());
+      assert!(AnyCaseHexString::new(any_case_hex.to_string()).is_ok());
+    }
+  }
+
+  #[test]
+  fn it_generates_hex_str_from_empty_input() {
+    let mut u = Unstructured::new(&[]);
+
+    assert_eq!(
+      UpperHexString::arbitrary(&mut u).unwrap(),
+      UpperHexString::default()
+    );
+  }
+}
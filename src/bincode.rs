@@ -0,0 +1,72 @@
+//! Binary serialization through the native `bincode` 2 traits.
+//!
+//! A [`HexString`] is encoded as its decoded bytes, prefixed by their length like a `Vec<u8>`,
+//! rather than as its text. The case is not encoded, so an
+//! [`AnyCaseHexString`](crate::AnyCaseHexString) is decoded back in lowercase.
+
+use ::bincode::de::{BorrowDecoder, Decoder};
+use ::bincode::enc::Encoder;
+use ::bincode::error::{DecodeError, EncodeError};
+use ::bincode::{BorrowDecode, Decode, Encode};
+
+use crate::{Case, HexString};
+
+impl<const C: Case> Encode for HexString<C> {
+  fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+    self.decode().encode(encoder)
+  }
+}
+
+impl<Context, const C: Case> Decode<Context> for HexString<C> {
+  fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+    Vec::<u8>::decode(decoder).map(Self::from)
+  }
+}
+
+impl<'de, Context, const C: Case> BorrowDecode<'de, Context> for HexString<C> {
+  fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+    decoder: &mut D,
+  ) -> Result<Self, DecodeError> {
+    <&[u8]>::borrow_decode(decoder).map(Self::from)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::bincode::config;
+
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_encodes_and_decodes_decoded_bytes() {
+    let hex = UpperHexString::new("DEADBEEF").unwrap();
+    let bytes = ::bincode::encode_to_vec(&hex, config::standard()).unwrap();
+
+    assert_eq!(bytes, vec![4, 0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(
+      ::bincode::decode_from_slice::<UpperHexString, _>(&bytes, config::standard()).unwrap(),
+      (hex.clone(), 5)
+    );
+    assert_eq!(
+      ::bincode::borrow_decode_from_slice::<UpperHexString, _>(&bytes, config::standard()).unwrap(),
+      (hex, 5)
+    );
+
+    let bytes =
+      ::bincode::encode_to_vec(AnyCaseHexString::new("DeAd").unwrap(), config::legacy()).unwrap();
+
+    assert_eq!(
+      ::bincode::decode_from_slice::<AnyCaseHexString, _>(&bytes, config::legacy()).unwrap(),
+      (AnyCaseHexString::new("dead").unwrap(), 10)
+    );
+  }
+
+  #[test]
+  fn it_fails_to_decode_truncated_bytes() {
+    assert!(::bincode::decode_from_slice::<LowerHexString, _>(
+      &[4, 0xde, 0xad],
+      config::standard()
+    )
+    .is_err());
+  }
+}
@@ -0,0 +1,53 @@
+//! Binary serialization through the `borsh` traits.
+//!
+//! A [`HexString`] is encoded as its decoded bytes, prefixed by their length like a `Vec<u8>`,
+//! rather than as its text. The case is not encoded, so an
+//! [`AnyCaseHexString`](crate::AnyCaseHexString) is read back in lowercase.
+
+use ::borsh::io::{Read, Result, Write};
+use ::borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{Case, HexString};
+
+impl<const C: Case> BorshSerialize for HexString<C> {
+  fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+    self.decode().serialize(writer)
+  }
+}
+
+impl<const C: Case> BorshDeserialize for HexString<C> {
+  fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+    Vec::<u8>::deserialize_reader(reader).map(Self::from)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_ser_and_deser_decoded_bytes() {
+    let hex = UpperHexString::new("DEADBEEF").unwrap();
+    let bytes = ::borsh::to_vec(&hex).unwrap();
+
+    assert_eq!(bytes, vec![4, 0, 0, 0, 0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(::borsh::from_slice::<UpperHexString>(&bytes).unwrap(), hex);
+    assert_eq!(
+      ::borsh::from_slice::<AnyCaseHexString>(
+        &::borsh::to_vec(&AnyCaseHexString::new("DeAd").unwrap()).unwrap()
+      )
+      .unwrap(),
+      AnyCaseHexString::new("dead").unwrap()
+    );
+    assert_eq!(
+      ::borsh::to_vec(&LowerHexString::default()).unwrap(),
+      vec![0, 0, 0, 0]
+    );
+  }
+
+  #[test]
+  fn it_fails_to_deser_truncated_bytes() {
+    assert!(::borsh::from_slice::<LowerHexString>(&[4, 0, 0, 0, 0xde, 0xad]).is_err());
+    assert!(::borsh::from_slice::<LowerHexString>(&[1, 0, 0, 0, 0xde, 0xad]).is_err());
+  }
+}
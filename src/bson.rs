@@ -0,0 +1,148 @@
+//! MongoDB interoperability through the `bson` crate.
+//!
+//! A [`HexString`] converts into a [`Bson::String`] or a [`Binary`] holding the decoded bytes, and
+//! the functions of this module store a [`HexString`] field as [`Binary`] with serde:
+//!
+//! ```
+//! # #![allow(incomplete_features)]
+//! # #![feature(adt_const_params)]
+//! use bson::{doc, Binary, Bson};
+//! use bson::spec::BinarySubtype;
+//! use hexstring::LowerHexString;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! struct Block {
+//!   #[serde(with = "hexstring::bson")]
+//!   hash: LowerHexString,
+//! }
+//!
+//! let block = Block { hash: LowerHexString::new("deadbeef").unwrap() };
+//! let document = bson::to_document(&block).unwrap();
+//!
+//! assert_eq!(
+//!   document.get("hash"),
+//!   Some(&Bson::Binary(Binary { subtype: BinarySubtype::Generic, bytes: vec![0xde, 0xad, 0xbe, 0xef] }))
+//! );
+//! assert_eq!(bson::from_document::<Block>(doc! { "hash": "deadbeef" }).unwrap(), block);
+//! ```
+
+use std::convert::TryFrom;
+
+use ::bson::spec::BinarySubtype;
+use ::bson::{Binary, Bson};
+use serde::de::{self, Deserialize, Deserializer, Unexpected};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{Case, HexString};
+
+/// Serializes a [`HexString`] as a generic [`Binary`] holding the decoded bytes.
+///
+/// Serializers other than the BSON ones receive the extended JSON representation of the
+/// [`Binary`].
+pub fn serialize<S: Serializer, const C: Case>(
+  s: &HexString<C>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  Binary::from(s.clone()).serialize(serializer)
+}
+
+/// Deserializes a [`HexString`] from either a [`Binary`] or a string.
+pub fn deserialize<'de, D: Deserializer<'de>, const C: Case>(
+  deserializer: D,
+) -> Result<HexString<C>, D::Error> {
+  HexString::try_from(Bson::deserialize(deserializer)?).map_err(de::Error::custom)
+}
+
+impl<const C: Case> From<HexString<C>> for Binary {
+  fn from(s: HexString<C>) -> Self {
+    Self {
+      subtype: BinarySubtype::Generic,
+      bytes: s.decode(),
+    }
+  }
+}
+
+impl<const C: Case> From<Binary> for HexString<C> {
+  fn from(binary: Binary) -> Self {
+    Self::from(binary.bytes)
+  }
+}
+
+impl<const C: Case> From<HexString<C>> for Bson {
+  fn from(s: HexString<C>) -> Self {
+    Self::String(s.into_cow().into_owned())
+  }
+}
+
+impl<const C: Case> TryFrom<Bson> for HexString<C> {
+  type Error = ::bson::de::Error;
+
+  /// Converts a [`Bson::String`] or a [`Bson::Binary`] into a [`HexString`].
+  ///
+  /// # Errors
+  /// This method fails if the value is neither a string nor a binary, or if the string is not a
+  /// valid hexadecimal of the expected case.
+  fn try_from(value: Bson) -> Result<Self, Self::Error> {
+    match value {
+      Bson::String(s) => Self::new(s).map_err(de::Error::custom),
+      Bson::Binary(binary) => Ok(Self::from(binary)),
+      value => Err(de::Error::invalid_type(
+        Unexpected::Other(&format!("{:?}", value.element_type())),
+        &"a hexadecimal string or a binary",
+      )),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::bson::doc;
+  use serde::{Deserialize, Serialize};
+
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[derive(Debug, Deserialize, PartialEq, Serialize)]
+  struct Block {
+    #[serde(with = "crate::bson")]
+    hash: UpperHexString,
+  }
+
+  #[test]
+  fn it_converts_from_and_into_bson() {
+    let hex = LowerHexString::new("dead").unwrap();
+    let binary = Binary::from(hex.clone());
+
+    assert_eq!(binary.subtype, BinarySubtype::Generic);
+    assert_eq!(binary.bytes, vec![0xde, 0xad]);
+    assert_eq!(LowerHexString::from(binary.clone()), hex);
+    assert_eq!(Bson::from(hex.clone()), Bson::String("dead".to_owned()));
+    assert_eq!(LowerHexString::try_from(Bson::Binary(binary)).unwrap(), hex);
+    assert_eq!(
+      LowerHexString::try_from(Bson::from(hex.clone())).unwrap(),
+      hex
+    );
+    assert!(LowerHexString::try_from(Bson::String("DEAD".to_owned())).is_err());
+    assert!(LowerHexString::try_from(Bson::Int32(42)).is_err());
+  }
+
+  #[test]
+  fn it_ser_and_deser_as_binary() {
+    let block = Block {
+      hash: UpperHexString::new("DEAD").unwrap(),
+    };
+    let document = ::bson::to_document(&block).unwrap();
+
+    assert_eq!(
+      document,
+      doc! { "hash": Binary { subtype: BinarySubtype::Generic, bytes: vec![0xde, 0xad] } }
+    );
+    assert_eq!(::bson::from_document::<Block>(document).unwrap(), block);
+    assert_eq!(
+      ::bson::from_document::<Block>(doc! { "hash": "DEAD" }).unwrap(),
+      block
+    );
+    assert!(::bson::from_document::<Block>(doc! { "hash": "dead" }).is_err());
+  }
+}
@@ -0,0 +1,192 @@
+//! Incremental construction of hexadecimal strings.
+
+use std::io;
+
+use crate::{Case, HexString};
+
+/// Builder of a [`HexString`] from bytes appended one after the other.
+///
+/// It implements [`io::Write`] so that any bytes written into it are encoded and appended, which
+/// allows to stream data of unknown length into a [`HexString`].
+///
+/// This struct is created by [`HexString::builder`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use hexstring::LowerHexString;
+///
+/// let mut builder = LowerHexString::builder();
+///
+/// builder.push_byte(0x01);
+/// io::copy(&mut &b"\xde\xad"[..], &mut builder).unwrap();
+///
+/// assert_eq!(builder.build(), LowerHexString::new("01dead").unwrap());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HexStringBuilder<const C: Case> {
+  s: String,
+}
+
+macro_rules! impl_push_int {
+  ($($ty:ident => $le:ident, $be:ident;)*) => {
+    $(
+      #[doc = concat!("Appends a `", stringify!($ty), "` as little-endian bytes.")]
+      pub fn $le(&mut self, value: $ty) -> &mut Self {
+        self.push_bytes(&value.to_le_bytes())
+      }
+
+      #[doc = concat!("Appends a `", stringify!($ty), "` as big-endian bytes.")]
+      pub fn $be(&mut self, value: $ty) -> &mut Self {
+        self.push_bytes(&value.to_be_bytes())
+      }
+    )*
+  };
+}
+
+impl<const C: Case> HexStringBuilder<C> {
+  /// Constructs a new empty [`HexStringBuilder`].
+  pub fn new() -> Self {
+    Self { s: String::new() }
+  }
+
+  /// Constructs a new empty [`HexStringBuilder`] with room for at least `len` bytes.
+  pub fn with_capacity(len: usize) -> Self {
+    Self {
+      s: String::with_capacity(len.saturating_mul(2)),
+    }
+  }
+
+  /// Appends a byte.
+  pub fn push_byte(&mut self, byte: u8) -> &mut Self {
+    self.push_bytes(&[byte])
+  }
+
+  /// Appends bytes.
+  pub fn push_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+    HexString::<C>::encode_into(bytes, &mut self.s);
+
+    self
+  }
+
+  impl_push_int! {
+    u16 => push_u16_le, push_u16_be;
+    u32 => push_u32_le, push_u32_be;
+    u64 => push_u64_le, push_u64_be;
+    u128 => push_u128_le, push_u128_be;
+  }
+
+  /// Returns the number of bytes appended so far.
+  pub fn byte_len(&self) -> usize {
+    self.s.len() / 2
+  }
+
+  /// Returns `true` if no byte has been appended.
+  pub fn is_empty(&self) -> bool {
+    self.s.is_empty()
+  }
+
+  /// Returns the hexadecimal string built so far.
+  pub fn as_str(&self) -> &str {
+    &self.s
+  }
+
+  /// Consumes the [`HexStringBuilder`], returning the built [`HexString`].
+  pub fn build(self) -> HexString<C> {
+    // SAFETY: the string is only extended with encoded bytes.
+    unsafe { HexString::new_unchecked(self.s) }
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Returns an empty [`HexStringBuilder`] of the same case.
+  pub fn builder() -> HexStringBuilder<C> {
+    HexStringBuilder::new()
+  }
+}
+
+impl<const C: Case> From<HexStringBuilder<C>> for HexString<C> {
+  fn from(builder: HexStringBuilder<C>) -> Self {
+    builder.build()
+  }
+}
+
+impl<const C: Case> io::Write for HexStringBuilder<C> {
+  /// Encodes and appends all the given bytes.
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    self.push_bytes(buf);
+
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Write;
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_builds_hex_str() {
+    let mut builder = HexStringBuilder::<{ Case::Upper }>::with_capacity(4);
+
+    assert!(builder.is_empty());
+
+    builder.push_byte(0xde).push_bytes(&[0xad, 0xbe]);
+
+    assert_eq!(builder.byte_len(), 3);
+    assert_eq!(builder.as_str(), "DEADBE");
+    assert_eq!(
+      UpperHexString::from(builder),
+      UpperHexString::new("DEADBE").unwrap()
+    );
+    assert_eq!(
+      HexStringBuilder::<{ Case::Any }>::new().build(),
+      AnyCaseHexString::default()
+    );
+  }
+
+  #[test]
+  fn it_pushes_integers() {
+    let mut builder = LowerHexString::builder();
+
+    builder
+      .push_u16_be(0xcafe)
+      .push_u16_le(0xcafe)
+      .push_u32_be(1)
+      .push_u32_le(1)
+      .push_u64_be(2)
+      .push_u64_le(2);
+
+    assert_eq!(
+      builder.as_str(),
+      "cafefeca000000010100000000000000000000020200000000000000"
+    );
+
+    let mut builder = UpperHexString::builder();
+
+    builder.push_u128_be(0xab).push_u128_le(0xab);
+
+    assert_eq!(
+      builder.build().to_string(),
+      format!("{0}ABAB{0}", "00".repeat(15))
+    );
+  }
+
+  #[test]
+  fn it_writes_encoded_bytes() {
+    let mut builder = LowerHexString::builder();
+
+    write!(builder, "A{}", 42).unwrap();
+    builder.write_all(&[0, 255]).unwrap();
+    builder.flush().unwrap();
+
+    assert_eq!(builder.build(), LowerHexString::new("41343200ff").unwrap());
+  }
+}
@@ -0,0 +1,101 @@
+//! Arena allocation of hexadecimal strings through the `bumpalo` crate.
+
+use ::bumpalo::Bump;
+
+use crate::{Case, Error, GenericHexString, HexString, LOWER_HEX_DIGITS, UPPER_HEX_DIGITS};
+
+/// Hexadecimal string allocated in a [`Bump`] arena.
+///
+/// It suits request-scoped parsers producing a large number of short hexadecimal strings, which
+/// are all freed at once along with the arena.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use bumpalo::Bump;
+/// use hexstring::{BumpHexString, Case};
+///
+/// let bump = Bump::new();
+/// let hex = BumpHexString::<{ Case::Lower }>::new_in("deadbeef", &bump).unwrap();
+/// let other_hex = BumpHexString::<{ Case::Lower }>::from_bytes_in(&[0xde, 0xad, 0xbe, 0xef], &bump);
+///
+/// assert_eq!(hex, other_hex);
+/// ```
+pub type BumpHexString<'bump, const C: Case> = GenericHexString<C, &'bump str>;
+
+impl<'bump, const C: Case> GenericHexString<C, &'bump str> {
+  /// Constructs a new [`BumpHexString`] by copying a string into the arena.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal of the expected case, in
+  /// which case nothing is allocated.
+  pub fn new_in(s: &str, bump: &'bump Bump) -> Result<Self, Error> {
+    HexString::<C>::validate(s)?;
+
+    Ok(unsafe { Self::new_unchecked(&*bump.alloc_str(s)) })
+  }
+
+  /// Constructs a new [`BumpHexString`] by encoding bytes into the arena.
+  pub fn from_bytes_in(bytes: &[u8], bump: &'bump Bump) -> Self {
+    let digits = match C {
+      Case::Upper => UPPER_HEX_DIGITS,
+      Case::Lower | Case::Any => LOWER_HEX_DIGITS,
+    };
+    let buffer = bump.alloc_slice_fill_copy(bytes.len() * 2, 0u8);
+
+    crate::encode_to_slice(bytes, buffer, digits);
+
+    // SAFETY: the buffer has been filled with hexadecimal characters only.
+    unsafe { Self::new_unchecked(std::str::from_utf8_unchecked(buffer)) }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::convert::TryFrom;
+
+  use super::*;
+  use crate::UpperHexString;
+
+  #[test]
+  fn it_allocates_in_arena() {
+    let bump = Bump::new();
+    let hex = BumpHexString::<{ Case::Upper }>::new_in("DEAD", &bump).unwrap();
+
+    assert_eq!(hex.as_str(), "DEAD");
+    assert!(bump.allocated_bytes() > 0);
+    assert_eq!(<[u8; 2]>::try_from(&hex), Ok([0xde, 0xad]));
+    assert_eq!(
+      UpperHexString::from(&hex),
+      UpperHexString::new("DEAD").unwrap()
+    );
+    assert_eq!(
+      BumpHexString::<{ Case::Upper }>::new_in("dead", &bump),
+      Err(Error::InvalidCase {
+        expected: Case::Upper,
+        found: Case::Lower,
+        index: 0
+      })
+    );
+  }
+
+  #[test]
+  fn it_encodes_bytes_in_arena() {
+    let bump = Bump::new();
+
+    assert_eq!(
+      BumpHexString::<{ Case::Lower }>::from_bytes_in(&[0xde, 0xad], &bump).as_str(),
+      "dead"
+    );
+    assert_eq!(
+      BumpHexString::<{ Case::Any }>::from_bytes_in(&[0xbe, 0xef], &bump).as_str(),
+      "beef"
+    );
+    assert_eq!(
+      BumpHexString::<{ Case::Upper }>::from_bytes_in(&[], &bump).as_str(),
+      ""
+    );
+  }
+}
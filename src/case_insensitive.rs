@@ -0,0 +1,125 @@
+//! Case-insensitive comparison and hashing.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::{Case, HexString};
+
+/// Wraps a [`HexString`] so that its equality, ordering and hashing ignore the case.
+///
+/// It allows maps and sets keyed by hexadecimal strings to hold a single entry for the same bytes,
+/// whatever the case of the strings.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use hexstring::{AnyCaseHexString, CaseInsensitive};
+///
+/// let mut set = HashSet::new();
+///
+/// set.insert(CaseInsensitive(AnyCaseHexString::new("deadbeef").unwrap()));
+/// set.insert(CaseInsensitive(AnyCaseHexString::new("DeadBeef").unwrap()));
+///
+/// assert_eq!(set.len(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct CaseInsensitive<T>(pub T);
+
+impl<T> CaseInsensitive<T> {
+  /// Consumes the [`CaseInsensitive`], returning the wrapped value.
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<const C: Case> From<HexString<C>> for CaseInsensitive<HexString<C>> {
+  fn from(s: HexString<C>) -> Self {
+    Self(s)
+  }
+}
+
+impl<const C: Case> fmt::Display for CaseInsensitive<HexString<C>> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&self.0, f)
+  }
+}
+
+impl<const C: Case, const C2: Case> PartialEq<CaseInsensitive<HexString<C2>>>
+  for CaseInsensitive<HexString<C>>
+{
+  fn eq(&self, other: &CaseInsensitive<HexString<C2>>) -> bool {
+    self.0.eq_ignore_case(&other.0)
+  }
+}
+
+impl<const C: Case> Eq for CaseInsensitive<HexString<C>> {}
+
+impl<const C: Case, const C2: Case> PartialOrd<CaseInsensitive<HexString<C2>>>
+  for CaseInsensitive<HexString<C>>
+{
+  fn partial_cmp(&self, other: &CaseInsensitive<HexString<C2>>) -> Option<Ordering> {
+    Some(lowercase_bytes(&self.0).cmp(lowercase_bytes(&other.0)))
+  }
+}
+
+impl<const C: Case> Ord for CaseInsensitive<HexString<C>> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    lowercase_bytes(&self.0).cmp(lowercase_bytes(&other.0))
+  }
+}
+
+impl<const C: Case> Hash for CaseInsensitive<HexString<C>> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    for b in lowercase_bytes(&self.0) {
+      state.write_u8(b);
+    }
+
+    // mimics `str` hashing to prevent prefix collisions.
+    state.write_u8(0xff);
+  }
+}
+
+fn lowercase_bytes<const C: Case>(s: &HexString<C>) -> impl Iterator<Item = u8> + '_ {
+  s.0.bytes().map(|b| b.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::hash_map::DefaultHasher;
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    value.hash(&mut hasher);
+    hasher.finish()
+  }
+
+  #[test]
+  fn it_compares_regardless_of_case() {
+    let lowercase_hex = CaseInsensitive(LowerHexString::new("0a1b").unwrap());
+    let uppercase_hex = CaseInsensitive(UpperHexString::new("0A1B").unwrap());
+    let any_case_hex = CaseInsensitive(AnyCaseHexString::new("0a1B").unwrap());
+
+    assert!(lowercase_hex == uppercase_hex);
+    assert!(uppercase_hex == any_case_hex);
+    assert!(lowercase_hex != CaseInsensitive(LowerHexString::new("0a1c").unwrap()));
+    assert!(any_case_hex < CaseInsensitive(UpperHexString::new("0A1C").unwrap()));
+  }
+
+  #[test]
+  fn it_hashes_regardless_of_case() {
+    let a = CaseInsensitive(AnyCaseHexString::new("DeadBeef").unwrap());
+    let b = CaseInsensitive(AnyCaseHexString::new("deadbeef").unwrap());
+    let c = CaseInsensitive(AnyCaseHexString::new("deadbeee").unwrap());
+
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(hash(&a), hash(&c));
+    assert_eq!(a.cmp(&b), Ordering::Equal);
+  }
+}
@@ -0,0 +1,211 @@
+//! Command-line argument parsing through the `clap` value parsers.
+
+use std::ffi::OsStr;
+use std::ops::RangeInclusive;
+
+use ::clap::builder::{TypedValueParser, ValueParserFactory};
+use ::clap::error::ErrorKind;
+use ::clap::{Arg, Command};
+
+use crate::{AnyCaseHexString, Case, HexString};
+
+/// Parses command-line arguments into [`HexString`], with error messages mentioning the expected
+/// format.
+///
+/// It is the default value parser of [`HexString`] arguments, and it can restrict the number of
+/// bytes or accept values of any case.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use clap::{Arg, Command};
+/// use hexstring::{Case, HexStringValueParser, LowerHexString};
+///
+/// let command = Command::new("sign").arg(
+///   Arg::new("key")
+///     .long("key")
+///     .value_parser(HexStringValueParser::<{ Case::Lower }>::new().exact_len(4).case_lenient()),
+/// );
+/// let matches = command.try_get_matches_from(["sign", "--key", "DeadBeef"]).unwrap();
+///
+/// assert_eq!(
+///   matches.get_one::<LowerHexString>("key"),
+///   Some(&LowerHexString::new("deadbeef").unwrap())
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct HexStringValueParser<const C: Case> {
+  len_range: RangeInclusive<usize>,
+  case_lenient: bool,
+}
+
+impl<const C: Case> HexStringValueParser<C> {
+  /// Constructs a new [`HexStringValueParser`] accepting any number of bytes of the expected case.
+  pub fn new() -> Self {
+    Self {
+      len_range: 0..=usize::MAX,
+      case_lenient: false,
+    }
+  }
+
+  /// Only accepts values of exactly `byte_len` bytes.
+  pub fn exact_len(self, byte_len: usize) -> Self {
+    self.len_range(byte_len..=byte_len)
+  }
+
+  /// Only accepts values whose number of bytes lies within the given range.
+  pub fn len_range(self, len_range: RangeInclusive<usize>) -> Self {
+    Self { len_range, ..self }
+  }
+
+  /// Accepts values of any case, converting them into the expected case.
+  pub fn case_lenient(self) -> Self {
+    Self {
+      case_lenient: true,
+      ..self
+    }
+  }
+
+  // Describes the values accepted by the parser.
+  fn expected(&self) -> String {
+    let case = match C {
+      _ if self.case_lenient => "",
+      Case::Lower => "lowercase ",
+      Case::Upper => "uppercase ",
+      Case::Any => "",
+    };
+    let (min, max) = (*self.len_range.start(), *self.len_range.end());
+    let len = match (min, max) {
+      (0, usize::MAX) => String::new(),
+      (min, usize::MAX) => format!(" of at least {} bytes", min),
+      (min, max) if min == max => format!(" of {} bytes", min),
+      (min, max) => format!(" of {} to {} bytes", min, max),
+    };
+
+    format!("a {}hexadecimal string{}", case, len)
+  }
+}
+
+impl<const C: Case> Default for HexStringValueParser<C> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<const C: Case> TypedValueParser for HexStringValueParser<C> {
+  type Value = HexString<C>;
+
+  fn parse_ref(
+    &self,
+    cmd: &Command,
+    arg: Option<&Arg>,
+    value: &OsStr,
+  ) -> Result<Self::Value, ::clap::Error> {
+    let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+    let s = value.to_str().ok_or_else(|| {
+      ::clap::Error::raw(
+        ErrorKind::InvalidUtf8,
+        format!("invalid UTF-8 value for '{}'\n", arg),
+      )
+      .with_cmd(cmd)
+    })?;
+    let hex = if self.case_lenient {
+      AnyCaseHexString::new_with_len_range(s.to_owned(), self.len_range.clone())
+        .map(AnyCaseHexString::to_case)
+    } else {
+      HexString::new_with_len_range(s.to_owned(), self.len_range.clone())
+    };
+
+    hex.map_err(|e| {
+      ::clap::Error::raw(
+        ErrorKind::ValueValidation,
+        format!(
+          "invalid value '{}' for '{}': {}, expected {}\n",
+          s,
+          arg,
+          e,
+          self.expected()
+        ),
+      )
+      .with_cmd(cmd)
+    })
+  }
+}
+
+impl<const C: Case> ValueParserFactory for HexString<C> {
+  type Parser = HexStringValueParser<C>;
+
+  fn value_parser() -> Self::Parser {
+    HexStringValueParser::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::clap::value_parser;
+
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  fn command(parser: impl TypedValueParser) -> Command {
+    Command::new("test").arg(Arg::new("key").long("key").value_parser(parser))
+  }
+
+  #[test]
+  fn it_parses_hex_str_arg() {
+    let matches = Command::new("test")
+      .arg(
+        Arg::new("key")
+          .long("key")
+          .value_parser(value_parser!(UpperHexString)),
+      )
+      .try_get_matches_from(["test", "--key", "DEAD"])
+      .unwrap();
+
+    assert_eq!(
+      matches.get_one::<UpperHexString>("key"),
+      Some(&UpperHexString::new("DEAD").unwrap())
+    );
+  }
+
+  #[test]
+  fn it_parses_hex_str_arg_of_any_case() {
+    let matches = command(HexStringValueParser::<{ Case::Upper }>::new().case_lenient())
+      .try_get_matches_from(["test", "--key", "dEaD"])
+      .unwrap();
+
+    assert_eq!(
+      matches.get_one::<UpperHexString>("key"),
+      Some(&UpperHexString::new("DEAD").unwrap())
+    );
+  }
+
+  #[test]
+  fn it_rejects_invalid_hex_str_arg() {
+    let error = command(HexStringValueParser::<{ Case::Lower }>::new().exact_len(2))
+      .try_get_matches_from(["test", "--key", "DEAD"])
+      .unwrap_err();
+
+    assert_eq!(error.kind(), ErrorKind::ValueValidation);
+    assert!(error.to_string().contains(
+      "invalid value 'DEAD' for '--key <key>': Invalid case at position 0 (expected Lower, found \
+       Upper), expected a lowercase hexadecimal string of 2 bytes"
+    ));
+
+    let error = command(HexStringValueParser::<{ Case::Lower }>::new().len_range(1..=2))
+      .try_get_matches_from(["test", "--key", "deadbeef"])
+      .unwrap_err();
+
+    assert!(error
+      .to_string()
+      .contains("expected a lowercase hexadecimal string of 1 to 2 bytes"));
+    assert!(command(HexStringValueParser::<{ Case::Lower }>::new())
+      .try_get_matches_from(["test", "--key", "dea"])
+      .is_err());
+    assert!(command(LowerHexString::value_parser())
+      .try_get_matches_from(["test", "--key", ""])
+      .is_ok());
+  }
+}
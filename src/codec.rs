@@ -0,0 +1,192 @@
+//! Newline-delimited hexadecimal framing through the `tokio-util` codec traits.
+
+use std::io;
+
+use bytes::{BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{Case, HexString};
+
+/// Codec of newline-delimited hexadecimal frames, to be used with
+/// [`Framed`](tokio_util::codec::Framed).
+///
+/// Each frame is decoded into a [`HexString`] of the expected case, ignoring a trailing carriage
+/// return. Both [`HexString`] and raw bytes can be encoded into frames.
+///
+/// Decoding fails with an [`io::ErrorKind::InvalidData`] error wrapping an [`Error`](crate::Error)
+/// if a frame is not a valid hexadecimal string.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::BytesMut;
+/// use hexstring::{Case, HexLineCodec, LowerHexString};
+/// use tokio_util::codec::{Decoder, Encoder};
+///
+/// let mut codec = HexLineCodec::<{ Case::Lower }>::new();
+/// let mut buf = BytesMut::new();
+///
+/// codec.encode(&[0xde, 0xad][..], &mut buf).unwrap();
+///
+/// assert_eq!(&buf[..], b"dead\n");
+/// assert_eq!(
+///   codec.decode(&mut buf).unwrap(),
+///   Some(LowerHexString::new("dead").unwrap())
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct HexLineCodec<const C: Case> {
+  // index from which to look for the next newline, so that partial frames are scanned only once.
+  next_index: usize,
+}
+
+impl<const C: Case> HexLineCodec<C> {
+  /// Constructs a new [`HexLineCodec`].
+  pub fn new() -> Self {
+    Self { next_index: 0 }
+  }
+
+  fn decode_line(line: &[u8]) -> Result<HexString<C>, io::Error> {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    // invalid UTF-8 sequences are replaced so that they are reported as invalid characters.
+    let s = String::from_utf8_lossy(line).into_owned();
+
+    HexString::new(s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+  }
+}
+
+impl<const C: Case> Decoder for HexLineCodec<C> {
+  type Item = HexString<C>;
+  type Error = io::Error;
+
+  fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+    match buf[self.next_index..].iter().position(|&b| b == b'\n') {
+      Some(offset) => {
+        let line = buf.split_to(self.next_index + offset + 1);
+
+        self.next_index = 0;
+        Self::decode_line(&line[..line.len() - 1]).map(Some)
+      }
+      None => {
+        self.next_index = buf.len();
+        Ok(None)
+      }
+    }
+  }
+
+  fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+    match self.decode(buf)? {
+      Some(hex) => Ok(Some(hex)),
+      None if buf.is_empty() => Ok(None),
+      None => {
+        let line = buf.split();
+
+        self.next_index = 0;
+        Self::decode_line(&line).map(Some)
+      }
+    }
+  }
+}
+
+impl<const C: Case, const C2: Case> Encoder<HexString<C2>> for HexLineCodec<C> {
+  type Error = io::Error;
+
+  fn encode(&mut self, hex: HexString<C2>, buf: &mut BytesMut) -> Result<(), Self::Error> {
+    let hex = hex.to_case::<C>();
+
+    buf.reserve(hex.0.len() + 1);
+    buf.put_slice(hex.0.as_bytes());
+    buf.put_u8(b'\n');
+
+    Ok(())
+  }
+}
+
+impl<const C: Case> Encoder<&[u8]> for HexLineCodec<C> {
+  type Error = io::Error;
+
+  fn encode(&mut self, bytes: &[u8], buf: &mut BytesMut) -> Result<(), Self::Error> {
+    self.encode(HexString::<C>::from(bytes), buf)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Error, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_decodes_frames() {
+    let mut codec = HexLineCodec::<{ Case::Upper }>::new();
+    let mut buf = BytesMut::from(&b"DEAD\r\nBE"[..]);
+
+    assert_eq!(
+      codec.decode(&mut buf).unwrap(),
+      Some(UpperHexString::new("DEAD").unwrap())
+    );
+    assert_eq!(codec.decode(&mut buf).unwrap(), None);
+
+    buf.extend_from_slice(b"EF\n\n00");
+
+    assert_eq!(
+      codec.decode(&mut buf).unwrap(),
+      Some(UpperHexString::new("BEEF").unwrap())
+    );
+    assert_eq!(
+      codec.decode(&mut buf).unwrap(),
+      Some(UpperHexString::default())
+    );
+    assert_eq!(codec.decode(&mut buf).unwrap(), None);
+    assert_eq!(
+      codec.decode_eof(&mut buf).unwrap(),
+      Some(UpperHexString::new("00").unwrap())
+    );
+    assert_eq!(codec.decode_eof(&mut buf).unwrap(), None);
+  }
+
+  #[test]
+  fn it_rejects_invalid_frames() {
+    let mut codec = HexLineCodec::<{ Case::Lower }>::new();
+    let error = codec
+      .decode(&mut BytesMut::from(&b"dEAD\n"[..]))
+      .unwrap_err();
+
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(
+      error.into_inner().unwrap().downcast_ref::<Error>(),
+      Some(&Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 1
+      })
+    );
+    assert_eq!(
+      codec
+        .decode(&mut BytesMut::from(&b"00\xff0\n"[..]))
+        .unwrap_err()
+        .into_inner()
+        .unwrap()
+        .downcast_ref::<Error>(),
+      Some(&Error::InvalidHexCharacter {
+        c: char::REPLACEMENT_CHARACTER,
+        index: 2
+      })
+    );
+  }
+
+  #[test]
+  fn it_encodes_frames() {
+    let mut codec = HexLineCodec::<{ Case::Lower }>::new();
+    let mut buf = BytesMut::new();
+
+    codec
+      .encode(UpperHexString::new("DEAD").unwrap(), &mut buf)
+      .unwrap();
+    codec
+      .encode(LowerHexString::new("beef").unwrap(), &mut buf)
+      .unwrap();
+    codec.encode(&[0x00, 0x11][..], &mut buf).unwrap();
+
+    assert_eq!(&buf[..], b"dead\nbeef\n0011\n");
+  }
+}
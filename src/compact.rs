@@ -0,0 +1,86 @@
+//! Compact owned storage of hexadecimal strings.
+
+use crate::{Case, GenericHexString, HexString};
+
+/// Owned hexadecimal string stored as a `Box<str>`, which takes two words instead of the four
+/// words of a [`HexString`].
+///
+/// It suits workloads holding a large number of hexadecimal strings in memory. It provides the
+/// same guarantees as [`HexString`] and converts from and into it.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use hexstring::{Case, CompactHexString, LowerHexString};
+///
+/// let hex = CompactHexString::<{ Case::Lower }>::new("deadbeef").unwrap();
+///
+/// assert_eq!(hex.as_str(), "deadbeef");
+/// assert_eq!(LowerHexString::from(hex), LowerHexString::new("deadbeef").unwrap());
+/// ```
+pub type CompactHexString<const C: Case> = GenericHexString<C, Box<str>>;
+
+impl<const C: Case> From<CompactHexString<C>> for HexString<C> {
+  fn from(s: CompactHexString<C>) -> Self {
+    unsafe { HexString::new_unchecked(String::from(s.into_storage())) }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::convert::TryFrom;
+  use std::mem::size_of;
+
+  use super::*;
+  use crate::{AnyCaseHexString, Error, UpperHexString};
+
+  #[test]
+  fn it_takes_two_words() {
+    assert_eq!(
+      size_of::<CompactHexString<{ Case::Lower }>>(),
+      2 * size_of::<usize>()
+    );
+  }
+
+  #[test]
+  fn it_constructs_from_str() {
+    assert_eq!(
+      CompactHexString::<{ Case::Upper }>::new("DEAD")
+        .unwrap()
+        .as_str(),
+      "DEAD"
+    );
+    assert_eq!(
+      "dEaD"
+        .parse::<CompactHexString<{ Case::Any }>>()
+        .unwrap()
+        .to_string(),
+      "dEaD"
+    );
+    assert_eq!(
+      CompactHexString::<{ Case::Upper }>::new(String::from("DEAd")),
+      Err(Error::InvalidCase {
+        expected: Case::Upper,
+        found: Case::Lower,
+        index: 3
+      })
+    );
+  }
+
+  #[test]
+  fn it_converts_from_and_into_bytes_and_hex_str() {
+    let hex = CompactHexString::<{ Case::Upper }>::from([0xde, 0xad]);
+
+    assert_eq!(<[u8; 2]>::try_from(&hex), Ok([0xde, 0xad]));
+    assert_eq!(
+      UpperHexString::from(hex.clone()),
+      UpperHexString::new("DEAD").unwrap()
+    );
+    assert_eq!(
+      CompactHexString::from(AnyCaseHexString::new("DeAd").unwrap()).as_str(),
+      "DeAd"
+    );
+  }
+}
@@ -0,0 +1,229 @@
+//! Cursor over the decoded bytes of hexadecimal strings.
+
+use std::io;
+
+use crate::{Case, HexString};
+
+/// Cursor reading typed values from the decoded bytes of a [`HexString`], which are decoded on
+/// demand.
+///
+/// The position is expressed in bytes. Reads past the end fail with an
+/// [`io::ErrorKind::UnexpectedEof`] error without moving the cursor.
+///
+/// This struct is created by [`HexString::cursor`].
+#[derive(Clone, Debug)]
+pub struct HexCursor<'a> {
+  s: &'a str,
+  pos: usize,
+}
+
+macro_rules! impl_read_int {
+  ($($ty:ident => $le:ident, $be:ident;)*) => {
+    $(
+      #[doc = concat!("Reads a little-endian `", stringify!($ty), "` and advances the cursor.")]
+      ///
+      /// # Errors
+      /// This method fails if there are not enough remaining bytes.
+      pub fn $le(&mut self) -> io::Result<$ty> {
+        let mut bytes = [0u8; std::mem::size_of::<$ty>()];
+
+        self.read_exact(&mut bytes)?;
+
+        Ok($ty::from_le_bytes(bytes))
+      }
+
+      #[doc = concat!("Reads a big-endian `", stringify!($ty), "` and advances the cursor.")]
+      ///
+      /// # Errors
+      /// This method fails if there are not enough remaining bytes.
+      pub fn $be(&mut self) -> io::Result<$ty> {
+        let mut bytes = [0u8; std::mem::size_of::<$ty>()];
+
+        self.read_exact(&mut bytes)?;
+
+        Ok($ty::from_be_bytes(bytes))
+      }
+    )*
+  };
+}
+
+impl HexCursor<'_> {
+  /// Returns the position of the cursor, in bytes.
+  pub fn position(&self) -> usize {
+    self.pos
+  }
+
+  /// Moves the cursor to the given position, in bytes.
+  ///
+  /// The position may be past the end, in which case further reads fail.
+  pub fn set_position(&mut self, pos: usize) {
+    self.pos = pos;
+  }
+
+  /// Returns the number of bytes after the position of the cursor.
+  pub fn remaining(&self) -> usize {
+    (self.s.len() / 2).saturating_sub(self.pos)
+  }
+
+  /// Reads exactly enough bytes to fill the buffer and advances the cursor.
+  ///
+  /// # Errors
+  /// This method fails if there are not enough remaining bytes.
+  pub fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+    if buf.is_empty() {
+      return Ok(());
+    }
+
+    if buf.len() > self.remaining() {
+      return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    let start = self.pos * 2;
+
+    crate::decode_to_slice(&self.s[start..start + buf.len() * 2], buf)
+      .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    self.pos += buf.len();
+
+    Ok(())
+  }
+
+  /// Reads a byte and advances the cursor.
+  ///
+  /// # Errors
+  /// This method fails if there is no remaining byte.
+  pub fn read_u8(&mut self) -> io::Result<u8> {
+    let mut bytes = [0u8; 1];
+
+    self.read_exact(&mut bytes)?;
+
+    Ok(bytes[0])
+  }
+
+  impl_read_int! {
+    u16 => read_u16_le, read_u16_be;
+    u32 => read_u32_le, read_u32_be;
+    u64 => read_u64_le, read_u64_be;
+  }
+}
+
+impl io::Read for HexCursor<'_> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    let len = buf.len().min(self.remaining());
+
+    HexCursor::read_exact(self, &mut buf[..len])?;
+
+    Ok(len)
+  }
+}
+
+impl io::Seek for HexCursor<'_> {
+  fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+    let invalid_seek = || {
+      io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "invalid seek to a negative or overflowing position",
+      )
+    };
+
+    let (base, offset) = match pos {
+      io::SeekFrom::Start(offset) => (0, i64::try_from(offset).map_err(|_| invalid_seek())?),
+      io::SeekFrom::End(offset) => (self.s.len() / 2, offset),
+      io::SeekFrom::Current(offset) => (self.pos, offset),
+    };
+
+    let pos = i64::try_from(base)
+      .ok()
+      .and_then(|base| base.checked_add(offset))
+      .filter(|&pos| pos >= 0)
+      .ok_or_else(invalid_seek)?;
+
+    self.pos = usize::try_from(pos).map_err(|_| invalid_seek())?;
+
+    // the position is known to be positive.
+    Ok(pos as u64)
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Returns a cursor reading typed values from the decoded bytes of the [`HexString`], without
+  /// decoding them into an intermediate buffer.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("cafe0100000002").unwrap();
+  /// let mut cursor = hex.cursor();
+  ///
+  /// assert_eq!(cursor.read_u16_be().unwrap(), 0xcafe);
+  /// assert_eq!(cursor.read_u8().unwrap(), 1);
+  /// assert_eq!(cursor.read_u32_be().unwrap(), 2);
+  /// assert!(cursor.read_u8().is_err());
+  /// ```
+  pub fn cursor(&self) -> HexCursor<'_> {
+    HexCursor { s: &self.0, pos: 0 }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::{Read, Seek, SeekFrom};
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString};
+
+  #[test]
+  fn it_reads_typed_values() {
+    let hex = AnyCaseHexString::new("01020304050607080A0b").unwrap();
+    let mut cursor = hex.cursor();
+
+    assert_eq!(cursor.read_u16_le().unwrap(), 0x0201);
+    assert_eq!(cursor.read_u32_be().unwrap(), 0x03040506);
+    assert_eq!(cursor.position(), 6);
+    assert_eq!(cursor.remaining(), 4);
+    assert_eq!(cursor.read_u16_be().unwrap(), 0x0708);
+
+    let mut bytes = [0u8; 2];
+
+    cursor.read_exact(&mut bytes).unwrap();
+
+    assert_eq!(bytes, [0x0a, 0x0b]);
+    assert_eq!(cursor.remaining(), 0);
+
+    cursor.set_position(0);
+
+    assert_eq!(cursor.read_u64_le().unwrap(), 0x0807060504030201);
+    assert_eq!(
+      cursor.read_u64_be().unwrap_err().kind(),
+      io::ErrorKind::UnexpectedEof
+    );
+    assert_eq!(cursor.position(), 8);
+  }
+
+  #[test]
+  fn it_reads_and_seeks() {
+    let hex = LowerHexString::new("deadbeef").unwrap();
+    let mut cursor = hex.cursor();
+    let mut buf = [0u8; 8];
+
+    assert_eq!(cursor.seek(SeekFrom::End(-3)).unwrap(), 1);
+    assert_eq!(Read::read(&mut cursor, &mut buf).unwrap(), 3);
+    assert_eq!(buf[..3], [0xad, 0xbe, 0xef]);
+    assert_eq!(Read::read(&mut cursor, &mut buf).unwrap(), 0);
+    assert_eq!(cursor.seek(SeekFrom::Current(-2)).unwrap(), 2);
+    assert_eq!(cursor.read_u8().unwrap(), 0xbe);
+    assert!(cursor.seek(SeekFrom::Current(-4)).is_err());
+    assert_eq!(
+      cursor.seek(SeekFrom::Start(u64::MAX)).unwrap_err().kind(),
+      io::ErrorKind::InvalidInput
+    );
+    assert!(cursor.seek(SeekFrom::Current(i64::MAX)).is_err());
+    assert_eq!(cursor.position(), 3);
+
+    cursor.set_position(10);
+
+    assert_eq!(cursor.remaining(), 0);
+    assert_eq!(Read::read(&mut cursor, &mut buf).unwrap(), 0);
+  }
+}
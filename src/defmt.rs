@@ -0,0 +1,24 @@
+//! Embedded logging through the `defmt` traits.
+
+use crate::{Case, HexString};
+
+impl<const C: Case> ::defmt::Format for HexString<C> {
+  /// Logs the [`HexString`] as its text, the same way as its `Display` implementation.
+  fn format(&self, fmt: ::defmt::Formatter<'_>) {
+    ::defmt::write!(fmt, "{=str}", &*self.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  fn assert_format<T: ::defmt::Format>() {}
+
+  #[test]
+  fn it_implements_format() {
+    assert_format::<LowerHexString>();
+    assert_format::<UpperHexString>();
+    assert_format::<AnyCaseHexString>();
+  }
+}
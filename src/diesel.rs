@@ -0,0 +1,134 @@
+//! Database integration through the `diesel` traits.
+//!
+//! A [`HexString`] can be stored either as a `Text` column or as a `Binary` column holding the
+//! decoded bytes, depending on the SQL type declared in the schema.
+
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{Binary, Text};
+
+use crate::{Case, HexString};
+
+// Derives `AsExpression` and `FromSqlRow` on `HexString` for both SQL types.
+#[allow(dead_code)]
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Text)]
+#[diesel(sql_type = Binary)]
+struct HexStringProxy<const C: Case>(HexString<C>);
+
+impl<DB, const C: Case> ToSql<Text, DB> for HexString<C>
+where
+  DB: Backend,
+  str: ToSql<Text, DB>,
+{
+  fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+    <str as ToSql<Text, DB>>::to_sql(&self.0, out)
+  }
+}
+
+impl<DB, const C: Case> FromSql<Text, DB> for HexString<C>
+where
+  DB: Backend,
+  String: FromSql<Text, DB>,
+{
+  fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+    Ok(Self::new(String::from_sql(bytes)?)?)
+  }
+}
+
+impl<DB, const C: Case> FromSql<Binary, DB> for HexString<C>
+where
+  DB: Backend,
+  Vec<u8>: FromSql<Binary, DB>,
+{
+  fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+    Ok(Self::from(Vec::<u8>::from_sql(bytes)?))
+  }
+}
+
+// Binary values are decoded on the fly, so they can't be borrowed from the `HexString` and have to
+// be written according to the bind collector of each backend.
+#[cfg(feature = "diesel-postgres")]
+impl<const C: Case> ToSql<Binary, diesel::pg::Pg> for HexString<C> {
+  fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::pg::Pg>) -> serialize::Result {
+    use std::io::Write;
+
+    out.write_all(&self.decode())?;
+
+    Ok(serialize::IsNull::No)
+  }
+}
+
+#[cfg(feature = "diesel-mysql")]
+impl<const C: Case> ToSql<Binary, diesel::mysql::Mysql> for HexString<C> {
+  fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::mysql::Mysql>) -> serialize::Result {
+    use std::io::Write;
+
+    out.write_all(&self.decode())?;
+
+    Ok(serialize::IsNull::No)
+  }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl<const C: Case> ToSql<Binary, diesel::sqlite::Sqlite> for HexString<C> {
+  fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::sqlite::Sqlite>) -> serialize::Result {
+    out.set_value(self.decode());
+
+    Ok(serialize::IsNull::No)
+  }
+}
+
+#[cfg(all(test, feature = "diesel-sqlite"))]
+mod tests {
+  use diesel::dsl::sql;
+  use diesel::sqlite::SqliteConnection;
+  use diesel::{Connection, IntoSql, RunQueryDsl};
+
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_stores_hex_str_as_text() {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    let hex = LowerHexString::new("deadbeef").unwrap();
+
+    assert_eq!(
+      diesel::select(hex.clone().into_sql::<Text>())
+        .get_result::<LowerHexString>(&mut conn)
+        .unwrap(),
+      hex
+    );
+    assert_eq!(
+      diesel::select(sql::<Text>("typeof(").bind::<Text, _>(&hex).sql(")"))
+        .get_result::<String>(&mut conn)
+        .unwrap(),
+      "text"
+    );
+    assert!(diesel::select(hex.into_sql::<Text>())
+      .get_result::<UpperHexString>(&mut conn)
+      .is_err());
+  }
+
+  #[test]
+  fn it_stores_hex_str_as_binary() {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    let hex = UpperHexString::new("DEADBEEF").unwrap();
+
+    assert_eq!(
+      diesel::select(hex.clone().into_sql::<Binary>())
+        .get_result::<UpperHexString>(&mut conn)
+        .unwrap(),
+      hex
+    );
+    assert_eq!(
+      diesel::select(hex.into_sql::<Binary>())
+        .get_result::<Vec<u8>>(&mut conn)
+        .unwrap(),
+      vec![0xde, 0xad, 0xbe, 0xef]
+    );
+  }
+}
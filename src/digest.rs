@@ -0,0 +1,90 @@
+//! Hash function integration through the `digest` traits.
+
+use std::io;
+
+use ::digest::Digest;
+
+use crate::{Case, HexString};
+
+impl<const C: Case> HexString<C> {
+  /// Constructs a new [`HexString`] from the hash of the given data.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  /// use sha2::Sha256;
+  ///
+  /// let hex = LowerHexString::digest::<Sha256>(b"hello world");
+  ///
+  /// assert_eq!(
+  ///   hex.to_string(),
+  ///   "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+  /// );
+  /// ```
+  pub fn digest<D: Digest>(data: impl AsRef<[u8]>) -> Self {
+    Self::from(D::digest(data))
+  }
+
+  /// Constructs a new [`HexString`] from the hash of all the data read from the given reader.
+  ///
+  /// # Errors
+  /// This method fails if reading from the reader fails.
+  pub fn hash_reader<D: Digest, R: io::Read>(mut reader: R) -> io::Result<Self> {
+    let mut hasher = D::new();
+    let mut buffer = [0u8; 8192];
+
+    loop {
+      match reader.read(&mut buffer) {
+        Ok(0) => break,
+        Ok(n) => hasher.update(&buffer[..n]),
+        Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+        Err(e) => return Err(e),
+      }
+    }
+
+    Ok(Self::from(hasher.finalize()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+  use sha2::{Sha256, Sha512};
+
+  const SHA256: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+  #[test]
+  fn it_constructs_from_digest_output() {
+    let hex = LowerHexString::from(Sha256::digest(b"hello world"));
+
+    assert_eq!(hex, LowerHexString::new(SHA256).unwrap());
+  }
+
+  #[test]
+  fn it_hashes_data() {
+    assert_eq!(
+      LowerHexString::digest::<Sha256>(b"hello world"),
+      LowerHexString::new(SHA256).unwrap()
+    );
+    assert_eq!(
+      UpperHexString::digest::<Sha256>("hello world"),
+      UpperHexString::new(SHA256.to_uppercase()).unwrap()
+    );
+  }
+
+  #[test]
+  fn it_hashes_reader() {
+    let data = vec![42u8; 100_000];
+
+    assert_eq!(
+      LowerHexString::hash_reader::<Sha512, _>(&data[..]).unwrap(),
+      LowerHexString::digest::<Sha512>(&data)
+    );
+    assert_eq!(
+      LowerHexString::hash_reader::<Sha256, _>(&b"hello world"[..]).unwrap(),
+      LowerHexString::new(SHA256).unwrap()
+    );
+  }
+}
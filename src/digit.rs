@@ -0,0 +1,239 @@
+//! Hexadecimal digits and pairs of digits.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Case, Error, LOWER_HEX_DIGITS, UPPER_HEX_DIGITS};
+
+/// Single hexadecimal digit, representing a nibble between 0 and 15.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use hexstring::{Case, HexDigit};
+///
+/// let digit = HexDigit::try_from('B').unwrap();
+///
+/// assert_eq!(digit.value(), 11);
+/// assert_eq!(digit.to_char(Case::Lower), 'b');
+/// assert_eq!(digit.to_string(), "b");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HexDigit(u8);
+
+impl HexDigit {
+  /// Constructs a new [`HexDigit`] from a value, returning `None` if it is greater than 15.
+  pub const fn new(value: u8) -> Option<Self> {
+    if value < 16 {
+      Some(Self(value))
+    } else {
+      None
+    }
+  }
+
+  /// Constructs a new [`HexDigit`] from the four low bits of a byte.
+  pub const fn from_low_bits(byte: u8) -> Self {
+    Self(byte & 0x0f)
+  }
+
+  /// Returns the value of the [`HexDigit`], between 0 and 15.
+  pub const fn value(self) -> u8 {
+    self.0
+  }
+
+  /// Returns the character of the [`HexDigit`] in the given case, lowercase for [`Case::Any`].
+  pub fn to_char(self, case: Case) -> char {
+    let digits = match case {
+      Case::Upper => UPPER_HEX_DIGITS,
+      Case::Lower | Case::Any => LOWER_HEX_DIGITS,
+    };
+
+    char::from(digits[usize::from(self.0)])
+  }
+}
+
+impl TryFrom<char> for HexDigit {
+  type Error = Error;
+
+  /// Converts a hexadecimal character of any case into a [`HexDigit`].
+  fn try_from(c: char) -> Result<Self, Self::Error> {
+    c.to_digit(16)
+      .map(|value| Self(value as u8))
+      .ok_or(Error::InvalidHexCharacter { c, index: 0 })
+  }
+}
+
+impl From<HexDigit> for u8 {
+  fn from(digit: HexDigit) -> Self {
+    digit.0
+  }
+}
+
+impl From<HexDigit> for char {
+  /// Converts the [`HexDigit`] into a lowercase character.
+  fn from(digit: HexDigit) -> Self {
+    digit.to_char(Case::Lower)
+  }
+}
+
+impl fmt::Display for HexDigit {
+  /// Formats the [`HexDigit`] as a lowercase character.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::LowerHex::fmt(self, f)
+  }
+}
+
+impl fmt::LowerHex for HexDigit {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::LowerHex::fmt(&self.0, f)
+  }
+}
+
+impl fmt::UpperHex for HexDigit {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::UpperHex::fmt(&self.0, f)
+  }
+}
+
+/// Pair of hexadecimal digits, representing one byte.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{Case, HexDigit, HexPair};
+///
+/// let pair: HexPair = "dE".parse().unwrap();
+///
+/// assert_eq!(u8::from(pair), 0xde);
+/// assert_eq!(pair.high(), HexDigit::new(0xd).unwrap());
+/// assert_eq!(pair.to_string(), "de");
+/// assert_eq!(format!("{:X}", HexPair::from(0x0a)), "0A");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HexPair(u8);
+
+impl HexPair {
+  /// Constructs a new [`HexPair`] from its high and low digits.
+  pub const fn new(high: HexDigit, low: HexDigit) -> Self {
+    Self(high.0 << 4 | low.0)
+  }
+
+  /// Returns the high digit of the [`HexPair`].
+  pub const fn high(self) -> HexDigit {
+    HexDigit(self.0 >> 4)
+  }
+
+  /// Returns the low digit of the [`HexPair`].
+  pub const fn low(self) -> HexDigit {
+    HexDigit::from_low_bits(self.0)
+  }
+
+  /// Returns the characters of the [`HexPair`] in the given case, lowercase for [`Case::Any`].
+  pub fn to_chars(self, case: Case) -> [char; 2] {
+    [self.high().to_char(case), self.low().to_char(case)]
+  }
+}
+
+impl From<u8> for HexPair {
+  fn from(byte: u8) -> Self {
+    Self(byte)
+  }
+}
+
+impl From<HexPair> for u8 {
+  fn from(pair: HexPair) -> Self {
+    pair.0
+  }
+}
+
+impl TryFrom<[char; 2]> for HexPair {
+  type Error = Error;
+
+  /// Converts two hexadecimal characters of any case into a [`HexPair`].
+  fn try_from([high, low]: [char; 2]) -> Result<Self, Self::Error> {
+    let high = HexDigit::try_from(high)?;
+    let low =
+      HexDigit::try_from(low).map_err(|_| Error::InvalidHexCharacter { c: low, index: 1 })?;
+
+    Ok(Self::new(high, low))
+  }
+}
+
+impl FromStr for HexPair {
+  type Err = Error;
+
+  /// Parses exactly two hexadecimal characters of any case into a [`HexPair`].
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut chars = s.chars();
+
+    match (chars.next(), chars.next(), chars.next()) {
+      (Some(high), Some(low), None) => Self::try_from([high, low]),
+      _ => Err(Error::InvalidStringLength),
+    }
+  }
+}
+
+impl fmt::Display for HexPair {
+  /// Formats the [`HexPair`] as two lowercase characters.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:02x}", self.0)
+  }
+}
+
+impl fmt::LowerHex for HexPair {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:02x}", self.0)
+  }
+}
+
+impl fmt::UpperHex for HexPair {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:02X}", self.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_converts_digit_from_and_into_char() {
+    assert_eq!(HexDigit::try_from('0'), Ok(HexDigit(0)));
+    assert_eq!(HexDigit::try_from('f'), Ok(HexDigit(15)));
+    assert_eq!(HexDigit::try_from('F'), Ok(HexDigit(15)));
+    assert_eq!(
+      HexDigit::try_from('g'),
+      Err(Error::InvalidHexCharacter { c: 'g', index: 0 })
+    );
+    assert_eq!(HexDigit::new(16), None);
+    assert_eq!(HexDigit::from_low_bits(0xab), HexDigit(0xb));
+    assert_eq!(char::from(HexDigit(10)), 'a');
+    assert_eq!(HexDigit(10).to_char(Case::Upper), 'A');
+    assert_eq!(format!("{} {:X}", HexDigit(12), HexDigit(12)), "c C");
+  }
+
+  #[test]
+  fn it_converts_pair_from_and_into_byte() {
+    let pair = HexPair::from(0xa5);
+
+    assert_eq!(pair.high(), HexDigit(0xa));
+    assert_eq!(pair.low(), HexDigit(0x5));
+    assert_eq!(HexPair::new(HexDigit(0xa), HexDigit(0x5)), pair);
+    assert_eq!(u8::from(pair), 0xa5);
+    assert_eq!(pair.to_chars(Case::Upper), ['A', '5']);
+    assert_eq!(format!("{} {:X}", HexPair::from(0x0b), pair), "0b A5");
+  }
+
+  #[test]
+  fn it_parses_pair() {
+    assert_eq!("aB".parse(), Ok(HexPair(0xab)));
+    assert_eq!(
+      "ag".parse::<HexPair>(),
+      Err(Error::InvalidHexCharacter { c: 'g', index: 1 })
+    );
+    assert_eq!("a".parse::<HexPair>(), Err(Error::InvalidStringLength));
+    assert_eq!("abc".parse::<HexPair>(), Err(Error::InvalidStringLength));
+  }
+}
@@ -0,0 +1,315 @@
+//! Display adapters for human-facing output.
+
+use std::fmt;
+use std::fmt::Write;
+
+use crate::{Case, HexString};
+
+/// Displays a byte slice as hexadecimal, without allocating a new string.
+///
+/// This struct is created by [`display`] and [`display_upper`].
+#[derive(Clone, Copy, Debug)]
+pub struct HexDisplay<'a> {
+  bytes: &'a [u8],
+  case: Case,
+}
+
+impl fmt::Display for HexDisplay<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.case {
+      Case::Upper => crate::encode_upper_into(self.bytes, f),
+      _ => crate::encode_into(self.bytes, f),
+    }
+  }
+}
+
+/// Displays the given bytes as lowercase hexadecimal, writing directly into the formatter.
+///
+/// # Examples
+///
+/// ```
+/// let bytes = [0xde, 0xad, 0xbe, 0xef];
+///
+/// assert_eq!(format!("payload={}", hexstring::display(&bytes)), "payload=deadbeef");
+/// ```
+pub fn display(bytes: &[u8]) -> HexDisplay<'_> {
+  HexDisplay {
+    bytes,
+    case: Case::Lower,
+  }
+}
+
+/// Displays the given bytes as uppercase hexadecimal, writing directly into the formatter.
+pub fn display_upper(bytes: &[u8]) -> HexDisplay<'_> {
+  HexDisplay {
+    bytes,
+    case: Case::Upper,
+  }
+}
+
+/// Displays a [`HexString`] by groups of bytes separated by a character.
+///
+/// This struct is created by [`HexString::grouped`].
+#[derive(Clone, Copy, Debug)]
+pub struct Grouped<'a> {
+  s: &'a str,
+  every_n_bytes: usize,
+  separator: char,
+}
+
+impl fmt::Display for Grouped<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let chunk_len = self.every_n_bytes.saturating_mul(2);
+
+    for (i, chunk) in chunks(self.s, chunk_len).enumerate() {
+      if i != 0 {
+        f.write_char(self.separator)?;
+      }
+
+      f.write_str(chunk)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Displays a [`HexString`] over several lines of a fixed number of bytes.
+///
+/// This struct is created by [`HexString::wrapped`].
+#[derive(Clone, Copy, Debug)]
+pub struct Wrapped<'a> {
+  s: &'a str,
+  bytes_per_line: usize,
+  indent: &'a str,
+}
+
+impl<'a> Wrapped<'a> {
+  /// Prefixes each line with the given indent.
+  pub fn with_indent(self, indent: &'a str) -> Self {
+    Self { indent, ..self }
+  }
+}
+
+impl fmt::Display for Wrapped<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let line_len = self.bytes_per_line.saturating_mul(2);
+
+    for (i, line) in chunks(self.s, line_len).enumerate() {
+      if i != 0 {
+        f.write_char('\n')?;
+      }
+
+      f.write_str(self.indent)?;
+      f.write_str(line)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Displays a [`HexString`] as its first and last bytes around an ellipsis.
+///
+/// This struct is created by [`HexString::abbreviated`].
+#[derive(Clone, Copy, Debug)]
+pub struct Abbreviated<'a> {
+  s: &'a str,
+  head: usize,
+  tail: usize,
+}
+
+impl<'a> Abbreviated<'a> {
+  // Returns the displayed head and tail, or `None` if the whole string is displayed.
+  pub(crate) fn split(&self) -> Option<(&'a str, &'a str)> {
+    let head_len = self.head.saturating_mul(2);
+    let tail_len = self.tail.saturating_mul(2);
+
+    if head_len.saturating_add(tail_len) >= self.s.len() {
+      return None;
+    }
+
+    Some((&self.s[..head_len], &self.s[self.s.len() - tail_len..]))
+  }
+
+  // Returns the whole abbreviated string.
+  pub(crate) fn as_str(&self) -> &'a str {
+    self.s
+  }
+}
+
+impl fmt::Display for Abbreviated<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.split() {
+      Some((head, tail)) => {
+        f.write_str(head)?;
+        f.write_char('…')?;
+        f.write_str(tail)
+      }
+      None => f.write_str(self.as_str()),
+    }
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Displays the [`HexString`] by groups of `every_n_bytes` bytes separated by `separator`,
+  /// without allocating a new string.
+  ///
+  /// The [`HexString`] is displayed as a single group if `every_n_bytes` is zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("deadbeef0011").unwrap();
+  ///
+  /// assert_eq!(hex.grouped(2, ' ').to_string(), "dead beef 0011");
+  /// assert_eq!(hex.grouped(1, ':').to_string(), "de:ad:be:ef:00:11");
+  /// ```
+  pub fn grouped(&self, every_n_bytes: usize, separator: char) -> Grouped<'_> {
+    Grouped {
+      s: &self.0,
+      every_n_bytes,
+      separator,
+    }
+  }
+
+  /// Displays the [`HexString`] over several lines of `bytes_per_line` bytes, without allocating
+  /// a new string.
+  ///
+  /// The [`HexString`] is displayed on a single line if `bytes_per_line` is zero. Lines are
+  /// separated by `\n` and no trailing newline is written.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("deadbeef0011").unwrap();
+  ///
+  /// assert_eq!(hex.wrapped(4).to_string(), "deadbeef\n0011");
+  /// assert_eq!(
+  ///   hex.wrapped(2).with_indent("  ").to_string(),
+  ///   "  dead\n  beef\n  0011"
+  /// );
+  /// ```
+  pub fn wrapped(&self, bytes_per_line: usize) -> Wrapped<'_> {
+    Wrapped {
+      s: &self.0,
+      bytes_per_line,
+      indent: "",
+    }
+  }
+
+  /// Displays the first `head` bytes and the last `tail` bytes of the [`HexString`] separated by
+  /// an ellipsis, without allocating a new string.
+  ///
+  /// The whole [`HexString`] is displayed if it is not longer than `head + tail` bytes.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("deadbeef0011deadbeef00").unwrap();
+  ///
+  /// assert_eq!(hex.abbreviated(3, 3).to_string(), "deadbe…beef00");
+  /// assert_eq!(hex.abbreviated(4, 0).to_string(), "deadbeef…");
+  /// ```
+  pub fn abbreviated(&self, head: usize, tail: usize) -> Abbreviated<'_> {
+    Abbreviated {
+      s: &self.0,
+      head,
+      tail,
+    }
+  }
+}
+
+// Splits a string into chunks of at most `len` characters, a zero `len` meaning a single chunk.
+fn chunks(s: &str, len: usize) -> impl Iterator<Item = &str> {
+  let len = if len == 0 { usize::MAX } else { len };
+  let mut rest = s;
+
+  std::iter::from_fn(move || {
+    if rest.is_empty() {
+      return None;
+    }
+
+    let (chunk, tail) = rest.split_at(len.min(rest.len()));
+
+    rest = tail;
+    Some(chunk)
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_displays_bytes() {
+    let bytes = (0..=255).collect::<Vec<u8>>();
+
+    assert_eq!(
+      display(&bytes).to_string(),
+      LowerHexString::from(&bytes[..]).to_string()
+    );
+    assert_eq!(
+      display_upper(&bytes).to_string(),
+      UpperHexString::from(&bytes[..]).to_string()
+    );
+    assert_eq!(format!("<{}>", display(&[])), "<>");
+  }
+
+  #[test]
+  fn it_displays_grouped_hex_str() {
+    let hex = LowerHexString::new("deadbeef0011").unwrap();
+
+    assert_eq!(hex.grouped(2, ' ').to_string(), "dead beef 0011");
+    assert_eq!(hex.grouped(1, ':').to_string(), "de:ad:be:ef:00:11");
+    assert_eq!(hex.grouped(5, '-').to_string(), "deadbeef00-11");
+    assert_eq!(hex.grouped(6, '-').to_string(), "deadbeef0011");
+    assert_eq!(hex.grouped(0, '-').to_string(), "deadbeef0011");
+    assert_eq!(hex.grouped(usize::MAX, '-').to_string(), "deadbeef0011");
+    assert_eq!(
+      UpperHexString::new("").unwrap().grouped(1, ':').to_string(),
+      ""
+    );
+  }
+
+  #[test]
+  fn it_displays_wrapped_hex_str() {
+    let hex = UpperHexString::new("DEADBEEF0011").unwrap();
+
+    assert_eq!(hex.wrapped(4).to_string(), "DEADBEEF\n0011");
+    assert_eq!(hex.wrapped(3).to_string(), "DEADBE\nEF0011");
+    assert_eq!(hex.wrapped(0).to_string(), "DEADBEEF0011");
+    assert_eq!(
+      hex.wrapped(5).with_indent("> ").to_string(),
+      "> DEADBEEF00\n> 11"
+    );
+    assert_eq!(
+      LowerHexString::new("")
+        .unwrap()
+        .wrapped(1)
+        .with_indent("  ")
+        .to_string(),
+      ""
+    );
+  }
+
+  #[test]
+  fn it_displays_abbreviated_hex_str() {
+    let hex = LowerHexString::new("deadbeef0011").unwrap();
+
+    assert_eq!(hex.abbreviated(2, 1).to_string(), "dead…11");
+    assert_eq!(hex.abbreviated(0, 2).to_string(), "…0011");
+    assert_eq!(hex.abbreviated(0, 0).to_string(), "…");
+    assert_eq!(hex.abbreviated(3, 3).to_string(), "deadbeef0011");
+    assert_eq!(hex.abbreviated(4, 4).to_string(), "deadbeef0011");
+    assert_eq!(
+      hex.abbreviated(usize::MAX, usize::MAX).to_string(),
+      "deadbeef0011"
+    );
+  }
+}
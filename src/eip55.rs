@@ -0,0 +1,250 @@
+//! EIP-55 checksummed Ethereum address support.
+
+use std::convert::{From, TryFrom};
+use std::str::FromStr;
+
+use derive_more::Display;
+use tiny_keccak::{Hasher, Keccak};
+
+use crate::{Case, Error, LowerHexString};
+
+/// Provides a structured representation of an Ethereum address checksummed according to
+/// [EIP-55](https://eips.ethereum.org/EIPS/eip-55).
+///
+/// It is guaranteed to be a 40 characters hexadecimal string (without `0x` prefix) whose
+/// alphabetic characters casing matches the checksum of the address.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::ChecksummedHexString;
+///
+/// let address = ChecksummedHexString::new("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+///
+/// assert!(ChecksummedHexString::new("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").is_err());
+/// ```
+///
+/// It converts from and into [`LowerHexString`].
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use hexstring::{ChecksummedHexString, LowerHexString};
+///
+/// let hex = LowerHexString::new("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+/// let address = ChecksummedHexString::try_from(hex.clone()).unwrap();
+///
+/// assert_eq!(address.to_string(), "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+/// assert_eq!(LowerHexString::from(address), hex);
+/// ```
+#[cfg_attr(
+  feature = "serde",
+  derive(serde::Deserialize, serde::Serialize),
+  serde(try_from = "String")
+)]
+#[derive(Clone, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[display(fmt = "{}", &self.0)]
+#[repr(transparent)]
+pub struct ChecksummedHexString(String);
+
+impl ChecksummedHexString {
+  /// Constructs a new [`ChecksummedHexString`] from a string.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a 40 characters hexadecimal string or if its
+  /// casing does not match the checksum.
+  pub fn new<S: Into<String>>(s: S) -> Result<Self, Error> {
+    let s = s.into();
+
+    if s.len() != 40 {
+      return Err(Error::InvalidStringLength);
+    }
+
+    if let Some((index, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+      return Err(Error::InvalidHexCharacter { c, index });
+    }
+
+    let expected = Self::checksum(s.to_ascii_lowercase());
+
+    if let Some((index, c)) = s
+      .char_indices()
+      .zip(expected.chars())
+      .find_map(|((index, c), expected)| (c != expected).then_some((index, expected)))
+    {
+      // only the case of an alphabetic character can differ from the checksum.
+      let (expected, found) = if c.is_ascii_uppercase() {
+        (Case::Upper, Case::Lower)
+      } else {
+        (Case::Lower, Case::Upper)
+      };
+
+      return Err(Error::InvalidCase {
+        expected,
+        found,
+        index,
+      });
+    }
+
+    Ok(Self(s))
+  }
+
+  /// Constructs a new [`ChecksummedHexString`] from the 20 bytes of an address.
+  pub fn from_address_bytes(bytes: [u8; 20]) -> Self {
+    Self(Self::checksum(hex::encode(bytes)))
+  }
+
+  // Applies the EIP-55 checksum casing on a lowercase hexadecimal string.
+  fn checksum(s: String) -> String {
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+
+    keccak.update(s.as_bytes());
+    keccak.finalize(&mut hash);
+
+    s.chars()
+      .enumerate()
+      .map(|(i, c)| match (hash[i / 2] >> (4 * (1 - i % 2))) & 0x0f {
+        8..=15 => c.to_ascii_uppercase(),
+        _ => c,
+      })
+      .collect()
+  }
+}
+
+impl FromStr for ChecksummedHexString {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::new(s)
+  }
+}
+
+impl From<ChecksummedHexString> for LowerHexString {
+  fn from(s: ChecksummedHexString) -> Self {
+    let mut s = s.0;
+
+    s.make_ascii_lowercase();
+
+    unsafe { LowerHexString::new_unchecked(s) }
+  }
+}
+
+impl TryFrom<LowerHexString> for ChecksummedHexString {
+  type Error = Error;
+
+  fn try_from(s: LowerHexString) -> Result<Self, Self::Error> {
+    <[u8; 20]>::try_from(s).map(Self::from_address_bytes)
+  }
+}
+
+impl From<ChecksummedHexString> for [u8; 20] {
+  fn from(s: ChecksummedHexString) -> Self {
+    let mut bytes = [0u8; 20];
+
+    // since `ChecksummedHexString` always represents a valid 20 bytes hexadecimal string, the
+    // result of `hex::decode_to_slice` can be safely unwrapped.
+    hex::decode_to_slice(&s.0, &mut bytes).unwrap();
+
+    bytes
+  }
+}
+
+// Hide `std::convert::TryFrom` conversion implementation from string used only by
+// `serde::Deserialize` mechanism.
+#[cfg(feature = "serde")]
+mod seal {
+  use super::*;
+
+  #[doc(hidden)]
+  impl TryFrom<String> for ChecksummedHexString {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+      Self::new(s)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Test vectors from EIP-55 specification.
+  const ADDRESSES: [&str; 4] = [
+    "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+    "fB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+    "dbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+    "D1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+  ];
+
+  #[test]
+  fn it_constructs_from_checksummed_str() {
+    for address in ADDRESSES {
+      assert_eq!(
+        ChecksummedHexString::new(address),
+        Ok(ChecksummedHexString(address.to_string()))
+      );
+    }
+  }
+
+  #[test]
+  fn it_rejects_str_with_invalid_checksum() {
+    assert_eq!(
+      ChecksummedHexString::new("5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+      Err(Error::InvalidCase {
+        expected: Case::Upper,
+        found: Case::Lower,
+        index: 2
+      })
+    );
+    assert_eq!(
+      ChecksummedHexString::new("5aAeb6053F3E94C9b9A09f33669435E7Ef1BEAed"),
+      Err(Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 36
+      })
+    );
+    assert_eq!(
+      ChecksummedHexString::new("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"),
+      Err(Error::InvalidStringLength)
+    );
+    assert_eq!(
+      ChecksummedHexString::new("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeZ"),
+      Err(Error::InvalidHexCharacter { c: 'Z', index: 39 })
+    );
+  }
+
+  #[test]
+  fn it_constructs_from_address_bytes() {
+    for address in ADDRESSES {
+      let mut bytes = [0u8; 20];
+
+      hex::decode_to_slice(address, &mut bytes).unwrap();
+
+      assert_eq!(
+        ChecksummedHexString::from_address_bytes(bytes).to_string(),
+        address
+      );
+      assert_eq!(
+        <[u8; 20]>::from(address.parse::<ChecksummedHexString>().unwrap()),
+        bytes
+      );
+    }
+  }
+
+  #[test]
+  fn it_converts_from_and_into_lower_hex_str() {
+    for address in ADDRESSES {
+      let hex = LowerHexString::new(address.to_lowercase()).unwrap();
+      let checksummed = ChecksummedHexString::try_from(hex.clone()).unwrap();
+
+      assert_eq!(checksummed.to_string(), address);
+      assert_eq!(LowerHexString::from(checksummed), hex);
+    }
+
+    assert_eq!(
+      ChecksummedHexString::try_from(LowerHexString::new("5aaeb6").unwrap()),
+      Err(Error::InvalidStringLength)
+    );
+  }
+}
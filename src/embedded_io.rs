@@ -0,0 +1,57 @@
+//! `no_std` streaming through the `embedded-io` traits.
+
+use ::embedded_io::{ErrorKind, ErrorType, Read};
+
+use crate::{Error, HexReader};
+
+impl ::embedded_io::Error for Error {
+  fn kind(&self) -> ErrorKind {
+    ErrorKind::InvalidData
+  }
+}
+
+impl ErrorType for HexReader<'_> {
+  type Error = Error;
+}
+
+impl Read for HexReader<'_> {
+  /// Reads the next decoded bytes.
+  ///
+  /// It fails only if the [`HexString`](crate::HexString) has been constructed from the
+  /// `new_unchecked` method with an invalid string.
+  fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+    self.read_bytes(buf)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::borrow::Cow;
+
+  use super::*;
+  use crate::{HexString, LowerHexString};
+
+  #[test]
+  fn it_reads_decoded_bytes() {
+    let hex = LowerHexString::new("deadbeef00").unwrap();
+    let mut reader = hex.reader();
+    let mut buf = [0u8; 3];
+
+    assert_eq!(Read::read(&mut reader, &mut buf), Ok(3));
+    assert_eq!(buf, [0xde, 0xad, 0xbe]);
+
+    reader.read_exact(&mut buf[..2]).unwrap();
+
+    assert_eq!(buf[..2], [0xef, 0x00]);
+    assert_eq!(Read::read(&mut reader, &mut buf), Ok(0));
+  }
+
+  #[test]
+  fn it_fails_to_read_invalid_unchecked_str() {
+    // bypasses the check of `new_unchecked` in debug builds.
+    let hex: LowerHexString = HexString(Cow::Borrowed("zz"));
+    let error = Read::read(&mut hex.reader(), &mut [0u8; 1]).unwrap_err();
+
+    assert_eq!(::embedded_io::Error::kind(&error), ErrorKind::InvalidData);
+  }
+}
@@ -0,0 +1,104 @@
+//! Encoding of bytes into any formatting sink, without heap allocation.
+
+use std::fmt;
+
+use crate::{LOWER_HEX_DIGITS, UPPER_HEX_DIGITS};
+
+// Number of bytes encoded at once into the stack buffer.
+const CHUNK_LEN: usize = 64;
+
+/// Writes the lowercase hexadecimal representation of the given bytes into a formatting sink,
+/// without any heap allocation.
+///
+/// # Errors
+/// This function fails if writing into the sink fails.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+///
+/// let mut s = String::from("0x");
+///
+/// hexstring::encode_into(&[0xde, 0xad], &mut s).unwrap();
+///
+/// assert_eq!(s, "0xdead");
+/// ```
+pub fn encode_into<W: fmt::Write + ?Sized>(bytes: &[u8], w: &mut W) -> fmt::Result {
+  write_digits(bytes, w, LOWER_HEX_DIGITS)
+}
+
+/// Writes the uppercase hexadecimal representation of the given bytes into a formatting sink,
+/// without any heap allocation.
+///
+/// # Errors
+/// This function fails if writing into the sink fails.
+pub fn encode_upper_into<W: fmt::Write + ?Sized>(bytes: &[u8], w: &mut W) -> fmt::Result {
+  write_digits(bytes, w, UPPER_HEX_DIGITS)
+}
+
+fn write_digits<W: fmt::Write + ?Sized>(bytes: &[u8], w: &mut W, digits: &[u8; 16]) -> fmt::Result {
+  let mut buffer = [0u8; CHUNK_LEN * 2];
+
+  for chunk in bytes.chunks(CHUNK_LEN) {
+    let out = &mut buffer[..chunk.len() * 2];
+
+    crate::encode_chunk(chunk, out, digits);
+
+    // SAFETY: the buffer only contains hexadecimal characters.
+    w.write_str(unsafe { std::str::from_utf8_unchecked(out) })?;
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct FixedBuffer {
+    buffer: [u8; 8],
+    len: usize,
+  }
+
+  impl fmt::Write for FixedBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+      let buffer = self
+        .buffer
+        .get_mut(self.len..self.len + s.len())
+        .ok_or(fmt::Error)?;
+
+      buffer.copy_from_slice(s.as_bytes());
+      self.len += s.len();
+
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn it_encodes_into_sink() {
+    let bytes = (0..=255).collect::<Vec<u8>>();
+    let mut s = String::new();
+
+    encode_into(&bytes, &mut s).unwrap();
+    encode_upper_into(&bytes, &mut s).unwrap();
+    encode_into(&[], &mut s).unwrap();
+
+    assert_eq!(s, hex::encode(&bytes) + &hex::encode_upper(&bytes));
+  }
+
+  #[test]
+  fn it_encodes_into_fixed_buffer() {
+    let mut buffer = FixedBuffer {
+      buffer: [0; 8],
+      len: 0,
+    };
+
+    assert_eq!(
+      encode_upper_into(&[0xde, 0xad, 0xbe, 0xef], &mut buffer),
+      Ok(())
+    );
+    assert_eq!(&buffer.buffer, b"DEADBEEF");
+    assert_eq!(encode_into(&[0x00], &mut buffer), Err(fmt::Error));
+  }
+}
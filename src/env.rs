@@ -0,0 +1,148 @@
+//! Construction of hexadecimal string from environment variables.
+
+use std::env::{self, VarError};
+
+use derive_more::Display;
+
+use crate::{Case, Error, HexString};
+
+/// Errors than can occurs during [`HexString`] construction from an environment variable.
+///
+/// Each variant mentions the name of the environment variable.
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+pub enum EnvError {
+  /// Indicates that the environment variable is not set.
+  #[display(fmt = "Environment variable {} is not set", _0)]
+  NotPresent(String),
+  /// Indicates that the environment variable is not valid Unicode.
+  #[display(fmt = "Environment variable {} is not valid Unicode", _0)]
+  NotUnicode(String),
+  /// Indicates that the environment variable is not a valid hexadecimal string.
+  ///
+  /// Positions reported by the error refer to the untrimmed value.
+  #[display(fmt = "Invalid environment variable {}: {}", name, error)]
+  InvalidValue {
+    /// The name of the environment variable.
+    name: String,
+    /// The underlying error.
+    error: Error,
+  },
+}
+
+impl std::error::Error for EnvError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Self::InvalidValue { error, .. } => Some(error),
+      _ => None,
+    }
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Constructs a new [`HexString`] from the value of an environment variable, ignoring its
+  /// leading and trailing whitespaces.
+  ///
+  /// # Errors
+  /// This method fails if the environment variable is not set, is not valid Unicode or is not a
+  /// valid hexadecimal string.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::{EnvError, Error, LowerHexString};
+  ///
+  /// std::env::set_var("API_KEY", " 0a0b0c\n");
+  ///
+  /// assert_eq!(
+  ///   LowerHexString::from_env("API_KEY"),
+  ///   Ok(LowerHexString::new("0a0b0c").unwrap())
+  /// );
+  ///
+  /// std::env::set_var("API_KEY", " 0a0b0x\n");
+  ///
+  /// let error = LowerHexString::from_env("API_KEY").unwrap_err();
+  ///
+  /// assert_eq!(
+  ///   error.to_string(),
+  ///   "Invalid environment variable API_KEY: Invalid character 'x' at position 6"
+  /// );
+  /// ```
+  pub fn from_env(name: &str) -> Result<Self, EnvError> {
+    Self::from_env_opt(name)?.ok_or_else(|| EnvError::NotPresent(name.to_owned()))
+  }
+
+  /// Constructs a new [`HexString`] from the value of an environment variable if it is set,
+  /// ignoring its leading and trailing whitespaces.
+  ///
+  /// # Errors
+  /// This method fails if the environment variable is not valid Unicode or is not a valid
+  /// hexadecimal string.
+  pub fn from_env_opt(name: &str) -> Result<Option<Self>, EnvError> {
+    let value = match env::var(name) {
+      Ok(value) => value,
+      Err(VarError::NotPresent) => return Ok(None),
+      Err(VarError::NotUnicode(_)) => return Err(EnvError::NotUnicode(name.to_owned())),
+    };
+    let trimmed = value.trim();
+    let offset = value.len() - value.trim_start().len();
+
+    Self::new(trimmed.to_owned())
+      .map(Some)
+      .map_err(|error| EnvError::InvalidValue {
+        name: name.to_owned(),
+        error: error.map_index(|index| index + offset),
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::UpperHexString;
+
+  #[test]
+  fn it_constructs_from_env() {
+    env::set_var("HEXSTRING_TEST_FROM_ENV", "\t0A0B ");
+
+    assert_eq!(
+      UpperHexString::from_env("HEXSTRING_TEST_FROM_ENV"),
+      Ok(UpperHexString::new("0A0B").unwrap())
+    );
+    assert_eq!(
+      UpperHexString::from_env_opt("HEXSTRING_TEST_FROM_ENV"),
+      Ok(Some(UpperHexString::new("0A0B").unwrap()))
+    );
+    assert_eq!(
+      UpperHexString::from_env("HEXSTRING_TEST_UNSET"),
+      Err(EnvError::NotPresent("HEXSTRING_TEST_UNSET".to_owned()))
+    );
+    assert_eq!(
+      UpperHexString::from_env_opt("HEXSTRING_TEST_UNSET"),
+      Ok(None)
+    );
+  }
+
+  #[test]
+  fn it_reports_invalid_env() {
+    env::set_var("HEXSTRING_TEST_INVALID_ENV", "  0a0B");
+
+    assert_eq!(
+      UpperHexString::from_env("HEXSTRING_TEST_INVALID_ENV"),
+      Err(EnvError::InvalidValue {
+        name: "HEXSTRING_TEST_INVALID_ENV".to_owned(),
+        error: Error::InvalidCase {
+          expected: Case::Upper,
+          found: Case::Lower,
+          index: 3
+        },
+      })
+    );
+    assert_eq!(
+      UpperHexString::from_env_opt("HEXSTRING_TEST_INVALID_ENV")
+        .unwrap_err()
+        .to_string(),
+      "Invalid environment variable HEXSTRING_TEST_INVALID_ENV: Invalid case at position 3 (expected \
+       Upper, found Lower)"
+    );
+  }
+}
@@ -0,0 +1,256 @@
+//! C foreign function interface.
+//!
+//! A hexadecimal string of any case is exposed to C through the opaque [`HexStringHandle`]
+//! pointer, created by [`hexstring_new`] or [`hexstring_from_bytes`] and released by
+//! [`hexstring_free`]. Fallible functions return a [`HexStringStatus`] code and write their result
+//! through an out-pointer.
+//!
+//! The C header can be generated with `cbindgen` from the `cbindgen.toml` at the crate root.
+//!
+//! # Examples
+//!
+//! ```c
+//! HexStringHandle *hex = NULL;
+//!
+//! if (hexstring_new("deadbeef", &hex) == HEX_STRING_STATUS_OK) {
+//!   uint8_t bytes[4];
+//!
+//!   hexstring_to_bytes(hex, bytes, sizeof(bytes));
+//!   hexstring_free(hex);
+//! }
+//! ```
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::slice;
+
+use crate::{AnyCaseHexString, Error};
+
+/// Opaque handle to a hexadecimal string of any case.
+#[derive(Debug)]
+pub struct HexStringHandle(AnyCaseHexString);
+
+/// Status codes returned by the foreign functions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HexStringStatus {
+  /// Indicates a successful call.
+  Ok = 0,
+  /// Indicates a null pointer argument.
+  NullPointer = 1,
+  /// Indicates a string which is not valid UTF-8.
+  InvalidUtf8 = 2,
+  /// Indicates a string containing a non-hexadecimal character.
+  InvalidHexCharacter = 3,
+  /// Indicates a string with an odd number of characters.
+  OddLength = 4,
+  /// Indicates a string whose length does not match the expected number of bytes.
+  InvalidLength = 5,
+  /// Indicates an output buffer too small to hold the result.
+  BufferTooSmall = 6,
+}
+
+impl From<Error> for HexStringStatus {
+  fn from(e: Error) -> Self {
+    match e {
+      Error::InvalidHexCharacter { .. } | Error::InvalidCase { .. } => Self::InvalidHexCharacter,
+      Error::OddLength => Self::OddLength,
+      Error::InvalidStringLength | Error::InvalidByteLength { .. } => Self::InvalidLength,
+      // reports the variants added later as an invalid string until they get their own status.
+      #[allow(unreachable_patterns)]
+      _ => Self::InvalidHexCharacter,
+    }
+  }
+}
+
+/// Constructs a new hexadecimal string from a NUL-terminated C string.
+///
+/// On success, the handle is written to `out` and must be released with [`hexstring_free`].
+///
+/// # Safety
+/// `s` must be null or point to a valid NUL-terminated string, and `out` must be null or valid
+/// for writes.
+#[no_mangle]
+pub unsafe extern "C" fn hexstring_new(
+  s: *const c_char,
+  out: *mut *mut HexStringHandle,
+) -> HexStringStatus {
+  if s.is_null() || out.is_null() {
+    return HexStringStatus::NullPointer;
+  }
+
+  let s = match CStr::from_ptr(s).to_str() {
+    Ok(s) => s,
+    Err(_) => return HexStringStatus::InvalidUtf8,
+  };
+
+  match AnyCaseHexString::new(s.to_owned()) {
+    Ok(hex) => {
+      *out = Box::into_raw(Box::new(HexStringHandle(hex)));
+
+      HexStringStatus::Ok
+    }
+    Err(e) => e.into(),
+  }
+}
+
+/// Constructs a new lowercase hexadecimal string from `len` bytes.
+///
+/// On success, the handle is written to `out` and must be released with [`hexstring_free`].
+///
+/// # Safety
+/// `bytes` must be null or valid for reads of `len` bytes, and `out` must be null or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn hexstring_from_bytes(
+  bytes: *const u8,
+  len: usize,
+  out: *mut *mut HexStringHandle,
+) -> HexStringStatus {
+  if bytes.is_null() || out.is_null() {
+    return HexStringStatus::NullPointer;
+  }
+
+  let hex = AnyCaseHexString::from(slice::from_raw_parts(bytes, len));
+
+  *out = Box::into_raw(Box::new(HexStringHandle(hex)));
+
+  HexStringStatus::Ok
+}
+
+/// Returns the number of bytes represented by the hexadecimal string, or zero if `handle` is null.
+///
+/// # Safety
+/// `handle` must be null or a live handle returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn hexstring_byte_len(handle: *const HexStringHandle) -> usize {
+  handle.as_ref().map_or(0, |handle| handle.0 .0.len() / 2)
+}
+
+/// Decodes the hexadecimal string into the `len` bytes long `out` buffer.
+///
+/// The buffer must hold at least [`hexstring_byte_len`] bytes.
+///
+/// # Safety
+/// `handle` must be null or a live handle returned by this module, and `out` must be null or
+/// valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn hexstring_to_bytes(
+  handle: *const HexStringHandle,
+  out: *mut u8,
+  len: usize,
+) -> HexStringStatus {
+  let handle = match handle.as_ref() {
+    Some(handle) if !out.is_null() => handle,
+    _ => return HexStringStatus::NullPointer,
+  };
+  let s = &handle.0 .0;
+
+  if len < s.len() / 2 {
+    return HexStringStatus::BufferTooSmall;
+  }
+
+  let out = slice::from_raw_parts_mut(out, s.len() / 2);
+
+  match crate::decode_to_slice(s, out) {
+    Ok(()) => HexStringStatus::Ok,
+    Err(e) => e.into(),
+  }
+}
+
+/// Releases a handle returned by this module. Does nothing if `handle` is null.
+///
+/// # Safety
+/// `handle` must be null or a live handle returned by this module, and must not be used afterward.
+#[no_mangle]
+pub unsafe extern "C" fn hexstring_free(handle: *mut HexStringHandle) {
+  if !handle.is_null() {
+    drop(Box::from_raw(handle));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::ptr;
+
+  use super::*;
+
+  #[test]
+  fn it_constructs_and_decodes_hex_str() {
+    let mut handle = ptr::null_mut();
+    let mut bytes = [0u8; 4];
+
+    unsafe {
+      assert_eq!(
+        hexstring_new(c"DeadBeef".as_ptr(), &mut handle),
+        HexStringStatus::Ok
+      );
+      assert_eq!(hexstring_byte_len(handle), 4);
+      assert_eq!(
+        hexstring_to_bytes(handle, bytes.as_mut_ptr(), 3),
+        HexStringStatus::BufferTooSmall
+      );
+      assert_eq!(
+        hexstring_to_bytes(handle, bytes.as_mut_ptr(), bytes.len()),
+        HexStringStatus::Ok
+      );
+      hexstring_free(handle);
+    }
+
+    assert_eq!(bytes, [0xde, 0xad, 0xbe, 0xef]);
+  }
+
+  #[test]
+  fn it_constructs_from_bytes() {
+    let mut handle = ptr::null_mut();
+    let bytes = [1u8, 2, 3];
+    let mut out = [0u8; 8];
+
+    unsafe {
+      assert_eq!(
+        hexstring_from_bytes(bytes.as_ptr(), bytes.len(), &mut handle),
+        HexStringStatus::Ok
+      );
+      assert_eq!((*handle).0.to_string(), "010203");
+      assert_eq!(
+        hexstring_to_bytes(handle, out.as_mut_ptr(), out.len()),
+        HexStringStatus::Ok
+      );
+      hexstring_free(handle);
+    }
+
+    assert_eq!(out[..3], bytes);
+  }
+
+  #[test]
+  fn it_reports_errors() {
+    let mut handle = ptr::null_mut();
+
+    unsafe {
+      assert_eq!(
+        hexstring_new(c"abc".as_ptr(), &mut handle),
+        HexStringStatus::OddLength
+      );
+      assert_eq!(
+        hexstring_new(c"zz".as_ptr(), &mut handle),
+        HexStringStatus::InvalidHexCharacter
+      );
+      assert_eq!(
+        hexstring_new(c"\xff\xff".as_ptr(), &mut handle),
+        HexStringStatus::InvalidUtf8
+      );
+      assert_eq!(
+        hexstring_new(ptr::null(), &mut handle),
+        HexStringStatus::NullPointer
+      );
+      assert_eq!(hexstring_byte_len(handle), 0);
+      assert_eq!(
+        hexstring_to_bytes(handle, [0u8; 1].as_mut_ptr(), 1),
+        HexStringStatus::NullPointer
+      );
+      hexstring_free(handle);
+    }
+
+    assert!(handle.is_null());
+  }
+}
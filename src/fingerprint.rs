@@ -0,0 +1,278 @@
+//! TLS and SSH fingerprint support.
+
+use std::fmt;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use derive_more::Display;
+
+use crate::{Case, Error, LowerHexString};
+
+/// Indicates the hash algorithm of a [`Fingerprint`].
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Algorithm {
+  /// Indicates a MD5 fingerprint of 16 bytes.
+  #[display(fmt = "MD5")]
+  Md5,
+  /// Indicates a SHA-1 fingerprint of 20 bytes.
+  #[display(fmt = "SHA1")]
+  Sha1,
+  /// Indicates a SHA-256 fingerprint of 32 bytes.
+  #[display(fmt = "SHA256")]
+  Sha256,
+  /// Indicates a SHA-384 fingerprint of 48 bytes.
+  #[display(fmt = "SHA384")]
+  Sha384,
+  /// Indicates a SHA-512 fingerprint of 64 bytes.
+  #[display(fmt = "SHA512")]
+  Sha512,
+}
+
+impl Algorithm {
+  /// Returns the number of bytes of a fingerprint computed with this algorithm.
+  pub const fn byte_len(self) -> usize {
+    match self {
+      Self::Md5 => 16,
+      Self::Sha1 => 20,
+      Self::Sha256 => 32,
+      Self::Sha384 => 48,
+      Self::Sha512 => 64,
+    }
+  }
+}
+
+impl FromStr for Algorithm {
+  type Err = FingerprintError;
+
+  /// Parses an [`Algorithm`] regardless of the case and of the hyphen, such as `sha256` or
+  /// `SHA-256`.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.replace('-', "").to_ascii_uppercase().as_str() {
+      "MD5" => Ok(Self::Md5),
+      "SHA1" => Ok(Self::Sha1),
+      "SHA256" => Ok(Self::Sha256),
+      "SHA384" => Ok(Self::Sha384),
+      "SHA512" => Ok(Self::Sha512),
+      _ => Err(FingerprintError::UnknownAlgorithm(s.to_owned())),
+    }
+  }
+}
+
+/// Errors than can occurs during [`Fingerprint`] parsing.
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+pub enum FingerprintError {
+  /// Indicates that the string does not follow any known fingerprint format.
+  #[display(fmt = "Invalid fingerprint format")]
+  InvalidFormat,
+  /// Indicates that the algorithm of the fingerprint is not supported.
+  #[display(fmt = "Unknown fingerprint algorithm {}", _0)]
+  UnknownAlgorithm(String),
+  /// Indicates that the value of the fingerprint is not a valid hexadecimal string of the
+  /// expected length.
+  #[display(fmt = "{}", _0)]
+  InvalidValue(Error),
+}
+
+impl std::error::Error for FingerprintError {}
+
+impl From<Error> for FingerprintError {
+  fn from(e: Error) -> Self {
+    Self::InvalidValue(e)
+  }
+}
+
+/// Provides a structured representation of a TLS or SSH fingerprint.
+///
+/// It is guaranteed to hold a hexadecimal value whose length matches its [`Algorithm`].
+///
+/// # Examples
+///
+/// A [`Fingerprint`] can be parsed from the `ALGORITHM:value` form or from the OpenSSL
+/// `algorithm Fingerprint=value` form, with or without colon separators and regardless of the case.
+///
+/// ```
+/// use hexstring::{Algorithm, Fingerprint};
+///
+/// let fingerprint: Fingerprint = "sha1 Fingerprint=0A:1B:2C:3D:4E:5F:60:71:82:93:A4:B5:C6:D7:E8:F9:0A:1B:2C:3D"
+///   .parse()
+///   .unwrap();
+///
+/// assert_eq!(fingerprint.algorithm(), Algorithm::Sha1);
+/// assert_eq!(
+///   fingerprint.to_string(),
+///   "SHA1:0a:1b:2c:3d:4e:5f:60:71:82:93:a4:b5:c6:d7:e8:f9:0a:1b:2c:3d"
+/// );
+/// assert_eq!(
+///   fingerprint,
+///   "SHA1:0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d".parse().unwrap()
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fingerprint {
+  algorithm: Algorithm,
+  value: LowerHexString,
+}
+
+impl Fingerprint {
+  /// Constructs a new [`Fingerprint`] from an algorithm and a value.
+  ///
+  /// # Errors
+  /// This method fails if the length of the value does not match the algorithm.
+  pub fn new(algorithm: Algorithm, value: LowerHexString) -> Result<Self, Error> {
+    if value.0.len() != algorithm.byte_len() * 2 {
+      return Err(Error::InvalidStringLength);
+    }
+
+    Ok(Self { algorithm, value })
+  }
+
+  /// Returns the algorithm of the [`Fingerprint`].
+  pub fn algorithm(&self) -> Algorithm {
+    self.algorithm
+  }
+
+  /// Returns the value of the [`Fingerprint`].
+  pub fn value(&self) -> &LowerHexString {
+    &self.value
+  }
+
+  /// Consumes the [`Fingerprint`], returning its value.
+  pub fn into_value(self) -> LowerHexString {
+    self.value
+  }
+
+  fn fmt_with_case(&self, f: &mut fmt::Formatter<'_>, case: Case) -> fmt::Result {
+    write!(f, "{}", self.algorithm)?;
+
+    for (i, c) in self.value.0.chars().enumerate() {
+      if i % 2 == 0 {
+        f.write_char(':')?;
+      }
+
+      f.write_char(match case {
+        Case::Upper => c.to_ascii_uppercase(),
+        Case::Lower | Case::Any => c,
+      })?;
+    }
+
+    Ok(())
+  }
+}
+
+impl fmt::Display for Fingerprint {
+  /// Formats the [`Fingerprint`] using the `ALGORITHM:value` form with lowercase colon separated
+  /// value, such as `SHA1:0a:1b:…`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.fmt_with_case(f, Case::Lower)
+  }
+}
+
+impl fmt::UpperHex for Fingerprint {
+  /// Formats the [`Fingerprint`] using the `ALGORITHM:VALUE` form with uppercase colon separated
+  /// value, such as `SHA1:0A:1B:…`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.fmt_with_case(f, Case::Upper)
+  }
+}
+
+impl FromStr for Fingerprint {
+  type Err = FingerprintError;
+
+  /// Parses a [`Fingerprint`] from one of the following forms :
+  /// - `SHA256:ab:cd:…` or `SHA256:abcd…`
+  /// - `sha1 Fingerprint=AB:CD:…`, as printed by OpenSSL
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (algorithm, value) = match s.split_once('=') {
+      Some((prefix, value)) => match prefix.split_once(' ') {
+        Some((algorithm, suffix)) if suffix.trim().eq_ignore_ascii_case("fingerprint") => {
+          (algorithm, value)
+        }
+        _ => return Err(FingerprintError::InvalidFormat),
+      },
+      None => s.split_once(':').ok_or(FingerprintError::InvalidFormat)?,
+    };
+
+    let algorithm = algorithm.trim().parse::<Algorithm>()?;
+    let value = value.trim();
+    let is_separated = value.contains(':');
+    let mut digits = String::with_capacity(algorithm.byte_len() * 2);
+
+    for (index, c) in value.char_indices() {
+      match c {
+        ':' if is_separated && index % 3 == 2 => {}
+        c if c.is_ascii_hexdigit() && !(is_separated && index % 3 == 2) => {
+          digits.push(c.to_ascii_lowercase())
+        }
+        c => return Err(Error::InvalidHexCharacter { c, index }.into()),
+      }
+    }
+
+    let value = LowerHexString::new(digits)?;
+
+    Ok(Self::new(algorithm, value)?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SHA1: &str = "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d";
+
+  #[test]
+  fn it_parses_all_forms() {
+    let expected = Fingerprint::new(Algorithm::Sha1, LowerHexString::new(SHA1).unwrap()).unwrap();
+
+    for s in [
+      "SHA1:0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d",
+      "sha1:0a:1b:2c:3d:4e:5f:60:71:82:93:a4:b5:c6:d7:e8:f9:0a:1b:2c:3d",
+      "SHA-1:0A:1B:2C:3D:4E:5F:60:71:82:93:A4:B5:C6:D7:E8:F9:0A:1B:2C:3D",
+      "sha1 Fingerprint=0A:1B:2C:3D:4E:5F:60:71:82:93:A4:B5:C6:D7:E8:F9:0A:1B:2C:3D",
+      "SHA1 fingerprint=0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d",
+    ] {
+      assert_eq!(s.parse::<Fingerprint>(), Ok(expected.clone()));
+    }
+  }
+
+  #[test]
+  fn it_rejects_invalid_forms() {
+    assert_eq!(
+      SHA1.parse::<Fingerprint>(),
+      Err(FingerprintError::InvalidFormat)
+    );
+    assert_eq!(
+      "sha1 digest=0a1b".parse::<Fingerprint>(),
+      Err(FingerprintError::InvalidFormat)
+    );
+    assert_eq!(
+      "CRC32:0a1b2c3d".parse::<Fingerprint>(),
+      Err(FingerprintError::UnknownAlgorithm("CRC32".to_string()))
+    );
+    assert_eq!(
+      "SHA256:0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d".parse::<Fingerprint>(),
+      Err(FingerprintError::InvalidValue(Error::InvalidStringLength))
+    );
+    assert_eq!(
+      "SHA1:0a:1b2c".parse::<Fingerprint>(),
+      Err(FingerprintError::InvalidValue(Error::InvalidHexCharacter {
+        c: '2',
+        index: 5
+      }))
+    );
+  }
+
+  #[test]
+  fn it_formats_fingerprint() {
+    let fingerprint =
+      Fingerprint::new(Algorithm::Sha1, LowerHexString::new(SHA1).unwrap()).unwrap();
+
+    assert_eq!(
+      fingerprint.to_string(),
+      "SHA1:0a:1b:2c:3d:4e:5f:60:71:82:93:a4:b5:c6:d7:e8:f9:0a:1b:2c:3d"
+    );
+    assert_eq!(
+      format!("{:X}", fingerprint),
+      "SHA1:0A:1B:2C:3D:4E:5F:60:71:82:93:A4:B5:C6:D7:E8:F9:0A:1B:2C:3D"
+    );
+  }
+}
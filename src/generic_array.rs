@@ -0,0 +1,57 @@
+//! `generic-array` interoperability.
+
+use std::convert::{From, TryFrom};
+
+use ::generic_array::{ArrayLength, GenericArray};
+
+use crate::{Case, Error, HexString};
+
+impl<const C: Case, N: ArrayLength<u8>> From<GenericArray<u8, N>> for HexString<C> {
+  fn from(bytes: GenericArray<u8, N>) -> Self {
+    Self::from(&bytes[..])
+  }
+}
+
+impl<const C: Case, N: ArrayLength<u8>> TryFrom<HexString<C>> for GenericArray<u8, N> {
+  type Error = Error;
+
+  fn try_from(s: HexString<C>) -> Result<Self, Self::Error> {
+    let mut bytes = GenericArray::default();
+
+    hex::decode_to_slice(s.0.as_ref(), &mut bytes)?;
+
+    Ok(bytes)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+  use ::generic_array::typenum::{U4, U5};
+
+  #[test]
+  fn it_constructs_from_generic_array() {
+    let bytes = GenericArray::<u8, U4>::from([20, 42, 2, 10]);
+
+    assert_eq!(
+      LowerHexString::from(bytes),
+      LowerHexString::new("142a020a").unwrap()
+    );
+    assert_eq!(
+      UpperHexString::from(bytes),
+      UpperHexString::new("142A020A").unwrap()
+    );
+  }
+
+  #[test]
+  fn it_converts_into_generic_array() {
+    let bytes = GenericArray::<u8, U4>::try_from(LowerHexString::new("142a020a").unwrap());
+
+    assert_eq!(bytes, Ok(GenericArray::from([20, 42, 2, 10])));
+
+    let bytes = GenericArray::<u8, U5>::try_from(UpperHexString::new("142A020A").unwrap());
+
+    assert_eq!(bytes, Err(Error::InvalidStringLength));
+  }
+}
@@ -0,0 +1,50 @@
+//! Secure random hexadecimal string generation through the `getrandom` crate.
+
+use crate::{Case, HexString};
+
+impl<const C: Case> HexString<C> {
+  /// Constructs a new [`HexString`] of `byte_len` random bytes read directly from the random
+  /// number generator of the operating system.
+  ///
+  /// The generated value is suitable for security-sensitive identifiers such as API keys, session
+  /// tokens or nonces.
+  ///
+  /// # Errors
+  /// This method fails if the random number generator of the operating system is unavailable.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let token = LowerHexString::random_secure(32).unwrap();
+  ///
+  /// assert_eq!(token.to_string().len(), 64);
+  /// ```
+  pub fn random_secure(byte_len: usize) -> Result<Self, ::getrandom::Error> {
+    let mut bytes = vec![0u8; byte_len];
+
+    ::getrandom::getrandom(&mut bytes)?;
+
+    Ok(Self::from(bytes))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_generates_secure_random_hex_str() {
+    let a = UpperHexString::random_secure(16).unwrap();
+    let b = UpperHexString::random_secure(16).unwrap();
+
+    assert_eq!(a.0.len(), 32);
+    assert!(UpperHexString::new(a.to_string()).is_ok());
+    assert_ne!(a, b);
+    assert_eq!(
+      LowerHexString::random_secure(0).unwrap(),
+      LowerHexString::default()
+    );
+  }
+}
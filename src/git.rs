@@ -0,0 +1,167 @@
+//! Git object id support.
+
+use std::convert::{From, TryFrom};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Case, Error, HexString};
+
+/// Provides a structured representation of a Git object id made of `N` bytes.
+///
+/// Use [`GitSha1Oid`] or [`GitSha256Oid`] type aliases rather than the raw [`GitOid`] type.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::GitSha1Oid;
+///
+/// let oid: GitSha1Oid = "e83c5163316f89bfbde7d9ab23ca2e25604af290".parse().unwrap();
+///
+/// assert_eq!(oid.short(7), "e83c516");
+/// assert!(oid.matches_prefix("e83c516"));
+/// assert!(!oid.matches_prefix("e83c517"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct GitOid<const N: usize>([u8; N]);
+
+/// Convenient alias type to represent a SHA-1 Git object id.
+pub type GitSha1Oid = GitOid<20>;
+
+/// Convenient alias type to represent a SHA-256 Git object id.
+pub type GitSha256Oid = GitOid<32>;
+
+impl<const N: usize> GitOid<N> {
+  /// Constructs a new [`GitOid`] from an array of bytes.
+  pub const fn new(bytes: [u8; N]) -> Self {
+    Self(bytes)
+  }
+
+  /// Returns the underlying bytes of the [`GitOid`].
+  pub const fn as_bytes(&self) -> &[u8; N] {
+    &self.0
+  }
+
+  /// Returns the abbreviated form of the [`GitOid`] made of its `len` first hexadecimal
+  /// characters.
+  ///
+  /// The whole object id is returned if `len` exceeds its length.
+  pub fn short(&self, len: usize) -> String {
+    let mut s = hex::encode(self.0);
+
+    s.truncate(len);
+    s
+  }
+
+  /// Checks whether the [`GitOid`] starts with the given abbreviated form, regardless of the case.
+  ///
+  /// The prefix may have an odd length, such as the 7 characters abbreviations printed by Git.
+  pub fn matches_prefix(&self, prefix: &str) -> bool {
+    prefix.len() <= N * 2
+      && prefix.chars().enumerate().all(|(i, c)| {
+        let nibble = (self.0[i / 2] >> (4 * (1 - i % 2))) & 0x0f;
+
+        c.to_digit(16) == Some(nibble as u32)
+      })
+  }
+}
+
+impl<const N: usize> fmt::Display for GitOid<N> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&hex::encode(self.0))
+  }
+}
+
+impl<const N: usize> FromStr for GitOid<N> {
+  type Err = Error;
+
+  /// Parses a [`GitOid`] from its full hexadecimal form, regardless of the case.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut bytes = [0u8; N];
+
+    hex::decode_to_slice(s, &mut bytes)?;
+
+    Ok(Self(bytes))
+  }
+}
+
+impl<const N: usize> From<[u8; N]> for GitOid<N> {
+  fn from(bytes: [u8; N]) -> Self {
+    Self(bytes)
+  }
+}
+
+impl<const N: usize> From<GitOid<N>> for [u8; N] {
+  fn from(oid: GitOid<N>) -> Self {
+    oid.0
+  }
+}
+
+impl<const C: Case, const N: usize> From<GitOid<N>> for HexString<C> {
+  fn from(oid: GitOid<N>) -> Self {
+    Self::from(oid.0)
+  }
+}
+
+impl<const C: Case, const N: usize> TryFrom<HexString<C>> for GitOid<N> {
+  type Error = Error;
+
+  fn try_from(s: HexString<C>) -> Result<Self, Self::Error> {
+    <[u8; N]>::try_from(s).map(Self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::LowerHexString;
+
+  const SHA1: &str = "e83c5163316f89bfbde7d9ab23ca2e25604af290";
+
+  #[test]
+  fn it_parses_oid() {
+    let oid = SHA1.parse::<GitSha1Oid>().unwrap();
+
+    assert_eq!(oid.to_string(), SHA1);
+    assert_eq!(SHA1.to_uppercase().parse::<GitSha1Oid>(), Ok(oid));
+    assert_eq!(
+      SHA1.parse::<GitSha256Oid>(),
+      Err(Error::InvalidStringLength)
+    );
+    assert_eq!(
+      "z83c5163316f89bfbde7d9ab23ca2e25604af290".parse::<GitSha1Oid>(),
+      Err(Error::InvalidHexCharacter { c: 'z', index: 0 })
+    );
+  }
+
+  #[test]
+  fn it_abbreviates_oid() {
+    let oid = SHA1.parse::<GitSha1Oid>().unwrap();
+
+    assert_eq!(oid.short(7), "e83c516");
+    assert_eq!(oid.short(0), "");
+    assert_eq!(oid.short(64), SHA1);
+  }
+
+  #[test]
+  fn it_matches_prefix() {
+    let oid = SHA1.parse::<GitSha1Oid>().unwrap();
+
+    assert!(oid.matches_prefix(""));
+    assert!(oid.matches_prefix("e83c516"));
+    assert!(oid.matches_prefix("E83C5163"));
+    assert!(oid.matches_prefix(SHA1));
+    assert!(!oid.matches_prefix("e83c517"));
+    assert!(!oid.matches_prefix("e83c51g"));
+    assert!(!oid.matches_prefix(&format!("{}0", SHA1)));
+  }
+
+  #[test]
+  fn it_converts_from_and_into_hex_str() {
+    let hex = LowerHexString::new(SHA1).unwrap();
+    let oid = GitSha1Oid::try_from(hex.clone()).unwrap();
+
+    assert_eq!(LowerHexString::from(oid), hex);
+    assert_eq!(GitSha256Oid::try_from(hex), Err(Error::InvalidStringLength));
+  }
+}
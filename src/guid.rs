@@ -0,0 +1,170 @@
+//! Windows GUID textual form support.
+
+use crate::{Case, Error, HexString};
+
+// Positions of the braces and hyphens in the braced form, such as
+// `{00112233-4455-6677-8899-AABBCCDDEEFF}`.
+const OPENING_BRACE: usize = 0;
+const CLOSING_BRACE: usize = 37;
+const HYPHENS: [usize; 4] = [9, 14, 19, 24];
+
+// Swaps the byte order of the first three fields of a GUID, which are stored in little-endian
+// but written in big-endian in the textual form.
+fn swap_fields(bytes: &mut [u8; 16]) {
+  bytes[..4].reverse();
+  bytes[4..6].reverse();
+  bytes[6..8].reverse();
+}
+
+impl<const C: Case> HexString<C> {
+  /// Constructs a new [`HexString`] from a braced GUID string, such as
+  /// `{00112233-4455-6677-8899-AABBCCDDEEFF}`.
+  ///
+  /// The resulting [`HexString`] represents the 16 bytes of the GUID as laid out in memory,
+  /// meaning that the first three fields are stored in little-endian.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid braced GUID of the expected case.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::UpperHexString;
+  ///
+  /// let hex = UpperHexString::from_guid("{00112233-4455-6677-8899-AABBCCDDEEFF}").unwrap();
+  ///
+  /// assert_eq!(hex.to_string(), "33221100554477668899AABBCCDDEEFF");
+  /// ```
+  pub fn from_guid(s: &str) -> Result<Self, Error> {
+    if s.len() != 38 {
+      return Err(Error::InvalidStringLength);
+    }
+
+    let mut digits = String::with_capacity(32);
+
+    for (index, c) in s.char_indices() {
+      let separator = match index {
+        OPENING_BRACE => Some('{'),
+        CLOSING_BRACE => Some('}'),
+        index if HYPHENS.contains(&index) => Some('-'),
+        _ => None,
+      };
+
+      match separator {
+        Some(separator) if c != separator => return Err(Error::InvalidHexCharacter { c, index }),
+        None if !Self::is_hex_char(c) => return Err(Self::invalid_char(c, index)),
+        _ => {}
+      }
+
+      if c.is_ascii_hexdigit() {
+        digits.push(c);
+      }
+    }
+
+    let mut bytes = [0u8; 16];
+
+    hex::decode_to_slice(digits, &mut bytes)?;
+    swap_fields(&mut bytes);
+
+    Ok(Self::from(bytes))
+  }
+
+  /// Formats the [`HexString`] as a braced GUID string, such as
+  /// `{00112233-4455-6677-8899-AABBCCDDEEFF}`.
+  ///
+  /// The [`HexString`] is expected to represent the 16 bytes of the GUID as laid out in memory,
+  /// meaning that the first three fields are stored in little-endian.
+  ///
+  /// # Errors
+  /// This method fails if the [`HexString`] does not represent exactly 16 bytes.
+  pub fn to_guid(&self) -> Result<String, Error> {
+    let mut bytes = [0u8; 16];
+
+    hex::decode_to_slice(self.0.as_ref(), &mut bytes)?;
+    swap_fields(&mut bytes);
+
+    let hex = Self::from(bytes);
+    let digits: &str = &hex.0;
+    let mut s = String::with_capacity(38);
+
+    s.push('{');
+    s.push_str(&digits[..8]);
+    s.push('-');
+    s.push_str(&digits[8..12]);
+    s.push('-');
+    s.push_str(&digits[12..16]);
+    s.push('-');
+    s.push_str(&digits[16..20]);
+    s.push('-');
+    s.push_str(&digits[20..]);
+    s.push('}');
+
+    Ok(s)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  const GUID: &str = "{00112233-4455-6677-8899-AABBCCDDEEFF}";
+
+  #[test]
+  fn it_constructs_from_guid() {
+    assert_eq!(
+      UpperHexString::from_guid(GUID),
+      UpperHexString::new("33221100554477668899AABBCCDDEEFF")
+    );
+    assert_eq!(
+      LowerHexString::from_guid(&GUID.to_lowercase()),
+      LowerHexString::new("33221100554477668899aabbccddeeff")
+    );
+  }
+
+  #[test]
+  fn it_rejects_invalid_guid() {
+    assert_eq!(
+      UpperHexString::from_guid("00112233-4455-6677-8899-AABBCCDDEEFF"),
+      Err(Error::InvalidStringLength)
+    );
+    assert_eq!(
+      UpperHexString::from_guid("(00112233-4455-6677-8899-AABBCCDDEEFF)"),
+      Err(Error::InvalidHexCharacter { c: '(', index: 0 })
+    );
+    assert_eq!(
+      UpperHexString::from_guid("{00112233-4455-6677-8899+AABBCCDDEEFF}"),
+      Err(Error::InvalidHexCharacter { c: '+', index: 24 })
+    );
+    assert_eq!(
+      UpperHexString::from_guid("{00112233-4455-6677-8899-AABBCCDDEEFf}"),
+      Err(Error::InvalidCase {
+        expected: Case::Upper,
+        found: Case::Lower,
+        index: 36
+      })
+    );
+  }
+
+  #[test]
+  fn it_formats_guid() {
+    assert_eq!(
+      UpperHexString::new("33221100554477668899AABBCCDDEEFF")
+        .unwrap()
+        .to_guid()
+        .unwrap(),
+      GUID
+    );
+    assert_eq!(
+      LowerHexString::new("33221100554477668899aabbccddeeff")
+        .unwrap()
+        .to_guid()
+        .unwrap(),
+      GUID.to_lowercase()
+    );
+    assert_eq!(
+      LowerHexString::new("3322").unwrap().to_guid(),
+      Err(Error::InvalidStringLength)
+    );
+  }
+}
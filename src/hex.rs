@@ -0,0 +1,104 @@
+//! Integration with the traits of the `hex` crate.
+
+use std::iter::FromIterator;
+
+use ::hex::{FromHex, ToHex};
+
+use crate::{Case, Error, HexString};
+
+/// Constructs a [`HexString`] from hexadecimal characters given as bytes, allowing it to be used
+/// wherever a [`FromHex`] bound is expected.
+///
+/// # Examples
+///
+/// ```
+/// use hex::FromHex;
+/// use hexstring::LowerHexString;
+///
+/// let hex = LowerHexString::from_hex(b"0a0b").unwrap();
+///
+/// assert_eq!(hex, LowerHexString::new("0a0b").unwrap());
+/// assert!(LowerHexString::from_hex("0A0B").is_err());
+/// ```
+impl<const C: Case> FromHex for HexString<C> {
+  type Error = Error;
+
+  fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+    Self::from_ascii(hex.as_ref().to_vec())
+  }
+}
+
+/// Encodes the bytes represented by a [`HexString`], which yields the hexadecimal string itself in
+/// the requested case.
+///
+/// # Examples
+///
+/// ```
+/// use hex::ToHex;
+/// use hexstring::LowerHexString;
+///
+/// let hex = LowerHexString::new("0a0b").unwrap();
+///
+/// assert_eq!(hex.encode_hex::<String>(), "0a0b");
+/// assert_eq!(hex.encode_hex_upper::<String>(), "0A0B");
+/// ```
+impl<const C: Case> ToHex for HexString<C> {
+  fn encode_hex<T: FromIterator<char>>(&self) -> T {
+    self.0.chars().map(|c| c.to_ascii_lowercase()).collect()
+  }
+
+  fn encode_hex_upper<T: FromIterator<char>>(&self) -> T {
+    self.0.chars().map(|c| c.to_ascii_uppercase()).collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  fn decode<T: FromHex>(hex: &str) -> Result<T, T::Error> {
+    T::from_hex(hex)
+  }
+
+  #[test]
+  fn it_constructs_from_hex() {
+    assert_eq!(
+      decode::<UpperHexString>("0A0B"),
+      Ok(UpperHexString::new("0A0B").unwrap())
+    );
+    assert_eq!(
+      AnyCaseHexString::from_hex(b"0a0B"),
+      Ok(AnyCaseHexString::new("0a0B").unwrap())
+    );
+    assert_eq!(decode::<LowerHexString>("0a0"), Err(Error::OddLength));
+    assert_eq!(
+      decode::<LowerHexString>("0a0B"),
+      Err(Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 3
+      })
+    );
+    assert_eq!(
+      LowerHexString::from_hex([b'0', 0xff]),
+      Err(Error::InvalidHexCharacter {
+        c: char::REPLACEMENT_CHARACTER,
+        index: 1
+      })
+    );
+    assert_eq!(
+      LowerHexString::from_hex("0é0"),
+      Err(Error::InvalidHexCharacter { c: 'é', index: 1 })
+    );
+  }
+
+  #[test]
+  fn it_encodes_to_hex() {
+    let hex = AnyCaseHexString::new("aBcD").unwrap();
+
+    assert_eq!(hex.encode_hex::<String>(), "abcd");
+    assert_eq!(hex.encode_hex_upper::<String>(), "ABCD");
+    assert_eq!(hex.encode_hex::<String>(), ::hex::encode(hex.decode()));
+  }
+}
@@ -0,0 +1,180 @@
+//! Common interface of the hexadecimal string types.
+
+use crate::{AnyHexString, Case, Error, GenericHexString, HexString, InlineHexString, Storage};
+
+/// Read-only interface shared by the hexadecimal string types, allowing generic code to accept any
+/// of them with a single bound.
+///
+/// This trait is sealed so that implementors always hold a valid hexadecimal string. It is
+/// implemented by:
+/// - [`HexString`] and [`AnyHexString`]
+/// - [`InlineHexString`] for fixed-capacity strings
+/// - [`GenericHexString`] for any [`Storage`], including borrowed `&str`
+/// - references to any of them
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use hexstring::{Case, HexLike, InlineHexString, LowerHexString};
+///
+/// fn describe(hex: impl HexLike) -> String {
+///   format!("{} ({} bytes, {:?})", hex.as_str(), hex.byte_len(), hex.case())
+/// }
+///
+/// let hex = LowerHexString::new("deadbeef").unwrap();
+/// let inline = InlineHexString::<{ Case::Upper }, 8>::new("0A0B").unwrap();
+///
+/// assert_eq!(describe(&hex), "deadbeef (4 bytes, Lower)");
+/// assert_eq!(describe(inline), "0A0B (2 bytes, Upper)");
+/// ```
+pub trait HexLike: sealed::Sealed {
+  /// Returns the hexadecimal string.
+  fn as_str(&self) -> &str;
+
+  /// Returns the case of the hexadecimal string.
+  ///
+  /// The case is detected from the string for a hexadecimal string of any case, refers to
+  /// [`Case::detect`] for more details.
+  fn case(&self) -> Case;
+
+  /// Returns the number of bytes represented by the hexadecimal string.
+  fn byte_len(&self) -> usize {
+    self.as_str().len() / 2
+  }
+
+  /// Decodes the hexadecimal string into the given slice.
+  ///
+  /// # Errors
+  /// This method fails if the slice length is not exactly [`HexLike::byte_len`].
+  fn decode_to_slice(&self, out: &mut [u8]) -> Result<(), Error> {
+    if out.len() != self.byte_len() {
+      return Err(Error::InvalidStringLength);
+    }
+
+    crate::decode_to_slice(self.as_str(), out)
+  }
+}
+
+mod sealed {
+  pub trait Sealed {}
+}
+
+// Returns the case of a string expected to be of the given case.
+fn case_of(s: &str, case: Case) -> Case {
+  match case {
+    Case::Any => Case::detect(s).unwrap_or(Case::Any),
+    case => case,
+  }
+}
+
+impl<const C: Case> sealed::Sealed for HexString<C> {}
+
+impl<const C: Case> HexLike for HexString<C> {
+  fn as_str(&self) -> &str {
+    &self.0
+  }
+
+  fn case(&self) -> Case {
+    HexString::case(self)
+  }
+}
+
+impl sealed::Sealed for AnyHexString {}
+
+impl HexLike for AnyHexString {
+  fn as_str(&self) -> &str {
+    match self {
+      Self::Lower(s) => &s.0,
+      Self::Upper(s) => &s.0,
+    }
+  }
+
+  fn case(&self) -> Case {
+    AnyHexString::case(self)
+  }
+}
+
+impl<const C: Case, const N: usize> sealed::Sealed for InlineHexString<C, N> {}
+
+impl<const C: Case, const N: usize> HexLike for InlineHexString<C, N> {
+  fn as_str(&self) -> &str {
+    InlineHexString::as_str(self)
+  }
+
+  fn case(&self) -> Case {
+    case_of(InlineHexString::as_str(self), C)
+  }
+}
+
+impl<const C: Case, S: Storage> sealed::Sealed for GenericHexString<C, S> {}
+
+impl<const C: Case, S: Storage> HexLike for GenericHexString<C, S> {
+  fn as_str(&self) -> &str {
+    GenericHexString::as_str(self)
+  }
+
+  fn case(&self) -> Case {
+    case_of(GenericHexString::as_str(self), C)
+  }
+}
+
+impl<T: HexLike + ?Sized> sealed::Sealed for &T {}
+
+impl<T: HexLike + ?Sized> HexLike for &T {
+  fn as_str(&self) -> &str {
+    (**self).as_str()
+  }
+
+  fn case(&self) -> Case {
+    (**self).case()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString};
+
+  fn decode(hex: impl HexLike) -> Result<Vec<u8>, Error> {
+    let mut bytes = vec![0u8; hex.byte_len()];
+
+    hex.decode_to_slice(&mut bytes)?;
+
+    Ok(bytes)
+  }
+
+  #[test]
+  fn it_abstracts_over_hex_types() {
+    let hex = LowerHexString::new("0a0b").unwrap();
+    let borrowed = GenericHexString::<{ Case::Upper }, &str>::new("0A0B").unwrap();
+    let inline = InlineHexString::<{ Case::Any }, 4>::new("0a0B").unwrap();
+
+    assert_eq!(decode(&hex), Ok(vec![10, 11]));
+    assert_eq!(decode(&borrowed), Ok(vec![10, 11]));
+    assert_eq!(decode(inline), Ok(vec![10, 11]));
+    assert_eq!(decode(AnyHexString::new("0A0B").unwrap()), Ok(vec![10, 11]));
+    assert_eq!(HexLike::as_str(&borrowed), "0A0B");
+    assert_eq!(HexLike::case(&borrowed), Case::Upper);
+    assert_eq!(HexLike::case(&inline), Case::Any);
+    assert_eq!(
+      HexLike::case(&AnyCaseHexString::new("0123").unwrap()),
+      Case::Lower
+    );
+  }
+
+  #[test]
+  fn it_fails_to_decode_into_slice_of_wrong_length() {
+    let hex = LowerHexString::new("0a0b").unwrap();
+
+    assert_eq!(
+      hex.decode_to_slice(&mut [0u8; 3]),
+      Err(Error::InvalidStringLength)
+    );
+    assert_eq!(
+      hex.decode_to_slice(&mut [0u8; 1]),
+      Err(Error::InvalidStringLength)
+    );
+  }
+}
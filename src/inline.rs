@@ -0,0 +1,240 @@
+//! Inline storage of short hexadecimal strings.
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use crate::{Case, Error, HexString, LOWER_HEX_DIGITS, UPPER_HEX_DIGITS};
+
+/// Hexadecimal string of at most `N` characters stored inline, without any heap allocation.
+///
+/// It suits short values such as hashes and ids, which are converted from and into bytes without
+/// touching the heap. It provides the same guarantees as [`HexString`] and converts from and into
+/// it.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use std::convert::TryFrom;
+/// use hexstring::{Case, InlineHexString};
+///
+/// type Sha256Hex = InlineHexString<{ Case::Lower }, 64>;
+///
+/// let hex = Sha256Hex::try_from(&[0xde, 0xad, 0xbe, 0xef][..]).unwrap();
+///
+/// assert_eq!(hex.as_str(), "deadbeef");
+/// assert_eq!(<[u8; 4]>::try_from(hex), Ok([0xde, 0xad, 0xbe, 0xef]));
+/// ```
+#[derive(Clone, Copy)]
+pub struct InlineHexString<const C: Case, const N: usize> {
+  // characters after `len` are always zero.
+  buffer: [u8; N],
+  len: usize,
+}
+
+impl<const C: Case, const N: usize> InlineHexString<C, N> {
+  /// Constructs a new [`InlineHexString`] from a string.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal of the expected case, or if
+  /// it is longer than `N` characters.
+  pub fn new(s: &str) -> Result<Self, Error> {
+    if s.len() > N {
+      return Err(Error::InvalidByteLength {
+        len: s.len() / 2,
+        min: 0,
+        max: N / 2,
+      });
+    }
+
+    HexString::<C>::validate(s)?;
+
+    let mut buffer = [0; N];
+
+    buffer[..s.len()].copy_from_slice(s.as_bytes());
+
+    Ok(Self {
+      buffer,
+      len: s.len(),
+    })
+  }
+
+  /// Returns the hexadecimal string.
+  pub fn as_str(&self) -> &str {
+    // SAFETY: the buffer only contains hexadecimal characters.
+    unsafe { std::str::from_utf8_unchecked(&self.buffer[..self.len]) }
+  }
+}
+
+impl<const C: Case, const N: usize> Default for InlineHexString<C, N> {
+  fn default() -> Self {
+    Self {
+      buffer: [0; N],
+      len: 0,
+    }
+  }
+}
+
+impl<const C: Case, const N: usize> fmt::Debug for InlineHexString<C, N> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("InlineHexString")
+      .field(&self.as_str())
+      .finish()
+  }
+}
+
+impl<const C: Case, const N: usize> fmt::Display for InlineHexString<C, N> {
+  /// Formats the [`InlineHexString`] the same way as a [`HexString`].
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    crate::fmt_hex_str(self.as_str(), f)
+  }
+}
+
+impl<const C: Case, const N: usize> PartialEq for InlineHexString<C, N> {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+
+impl<const C: Case, const N: usize> Eq for InlineHexString<C, N> {}
+
+impl<const C: Case, const N: usize> PartialOrd for InlineHexString<C, N> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<const C: Case, const N: usize> Ord for InlineHexString<C, N> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.as_str().cmp(other.as_str())
+  }
+}
+
+impl<const C: Case, const N: usize> Hash for InlineHexString<C, N> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state)
+  }
+}
+
+impl<const C: Case, const N: usize> FromStr for InlineHexString<C, N> {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::new(s)
+  }
+}
+
+impl<const C: Case, const N: usize> TryFrom<&[u8]> for InlineHexString<C, N> {
+  type Error = Error;
+
+  /// Encodes the bytes into an [`InlineHexString`], in lowercase for [`Case::Any`].
+  fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+    let len = bytes.len() * 2;
+
+    if len > N {
+      return Err(Error::InvalidByteLength {
+        len: bytes.len(),
+        min: 0,
+        max: N / 2,
+      });
+    }
+
+    let digits = match C {
+      Case::Upper => UPPER_HEX_DIGITS,
+      Case::Lower | Case::Any => LOWER_HEX_DIGITS,
+    };
+    let mut buffer = [0; N];
+
+    crate::encode_chunk(bytes, &mut buffer[..len], digits);
+
+    Ok(Self { buffer, len })
+  }
+}
+
+impl<const C: Case, const N: usize, const M: usize> TryFrom<InlineHexString<C, N>> for [u8; M] {
+  type Error = Error;
+
+  fn try_from(s: InlineHexString<C, N>) -> Result<Self, Self::Error> {
+    let mut bytes = [0u8; M];
+
+    hex::decode_to_slice(s.as_str(), &mut bytes)?;
+
+    Ok(bytes)
+  }
+}
+
+impl<const C: Case, const N: usize> From<InlineHexString<C, N>> for HexString<C> {
+  fn from(s: InlineHexString<C, N>) -> Self {
+    unsafe { HexString::new_unchecked(s.as_str().to_owned()) }
+  }
+}
+
+impl<const C: Case, const N: usize> TryFrom<&HexString<C>> for InlineHexString<C, N> {
+  type Error = Error;
+
+  fn try_from(s: &HexString<C>) -> Result<Self, Self::Error> {
+    Self::new(&s.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  type Hash4 = InlineHexString<{ Case::Lower }, 8>;
+
+  #[test]
+  fn it_constructs_from_str() {
+    assert_eq!(Hash4::new("deadbeef").unwrap().as_str(), "deadbeef");
+    assert_eq!("dead".parse::<Hash4>().unwrap().as_str(), "dead");
+    assert_eq!(Hash4::default().as_str(), "");
+    assert_eq!(
+      Hash4::new("deadbeef00"),
+      Err(Error::InvalidByteLength {
+        len: 5,
+        min: 0,
+        max: 4
+      })
+    );
+    assert_eq!(Hash4::new("dea"), Err(Error::OddLength));
+    assert_eq!(
+      Hash4::new("deAD"),
+      Err(Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 2
+      })
+    );
+  }
+
+  #[test]
+  fn it_round_trips_bytes() {
+    let hex = InlineHexString::<{ Case::Upper }, 8>::try_from(&[0xde, 0xad][..]).unwrap();
+
+    assert_eq!(hex.as_str(), "DEAD");
+    assert_eq!(format!("{:>6}", hex), "  DEAD");
+    assert_eq!(format!("{:?}", hex), "InlineHexString(\"DEAD\")");
+    assert_eq!(<[u8; 2]>::try_from(hex), Ok([0xde, 0xad]));
+    assert_eq!(<[u8; 3]>::try_from(hex), Err(Error::InvalidStringLength));
+    assert!(Hash4::try_from(&[0; 5][..]).is_err());
+  }
+
+  #[test]
+  fn it_converts_from_and_into_hex_str() {
+    let hex = LowerHexString::new("deadbeef").unwrap();
+    let inline_hex = Hash4::try_from(&hex).unwrap();
+
+    assert_eq!(LowerHexString::from(inline_hex), hex);
+    assert_eq!(inline_hex, Hash4::new("deadbeef").unwrap());
+    assert!(inline_hex > Hash4::new("dead").unwrap());
+    assert!(
+      InlineHexString::<{ Case::Upper }, 2>::try_from(&UpperHexString::new("DEAD").unwrap())
+        .is_err()
+    );
+  }
+}
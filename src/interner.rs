@@ -0,0 +1,178 @@
+//! Interning of repeated hexadecimal strings.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::{Case, Error, HexString, SharedHexString};
+
+/// Pool deduplicating equal hexadecimal strings.
+///
+/// Each distinct string is stored once and handed out as [`InternedHex`] handles, which suits
+/// indexers and log processors seeing the same values over and over.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use hexstring::{Case, HexInterner, LowerHexString};
+///
+/// let mut interner = HexInterner::<{ Case::Lower }>::new();
+/// let a = interner.intern(&LowerHexString::new("deadbeef").unwrap());
+/// let b = interner.intern_str("deadbeef").unwrap();
+///
+/// assert_eq!(a, b);
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HexInterner<const C: Case> {
+  strings: HashSet<Arc<str>>,
+}
+
+impl<const C: Case> HexInterner<C> {
+  /// Constructs a new empty [`HexInterner`].
+  pub fn new() -> Self {
+    Self {
+      strings: HashSet::new(),
+    }
+  }
+
+  /// Returns the number of distinct strings in the pool.
+  pub fn len(&self) -> usize {
+    self.strings.len()
+  }
+
+  /// Returns `true` if the pool holds no string.
+  pub fn is_empty(&self) -> bool {
+    self.strings.is_empty()
+  }
+
+  /// Interns a [`HexString`], storing it only if no equal string is already in the pool.
+  pub fn intern(&mut self, s: &HexString<C>) -> InternedHex<C> {
+    unsafe { self.intern_unchecked(&s.0) }
+  }
+
+  /// Interns a string, storing it only if no equal string is already in the pool.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal of the expected case.
+  pub fn intern_str(&mut self, s: &str) -> Result<InternedHex<C>, Error> {
+    HexString::<C>::validate(s)?;
+
+    Ok(unsafe { self.intern_unchecked(s) })
+  }
+
+  /// Returns the handle of the given string if it is in the pool.
+  pub fn get(&self, s: &str) -> Option<InternedHex<C>> {
+    self.strings.get(s).cloned().map(InternedHex)
+  }
+
+  // SAFETY: the string should be a valid hexadecimal string of the expected case.
+  unsafe fn intern_unchecked(&mut self, s: &str) -> InternedHex<C> {
+    if let Some(interned) = self.strings.get(s) {
+      return InternedHex(interned.clone());
+    }
+
+    let interned = Arc::<str>::from(s);
+
+    self.strings.insert(interned.clone());
+
+    InternedHex(interned)
+  }
+}
+
+/// Handle to a hexadecimal string of a [`HexInterner`].
+///
+/// Its equality and hashing compare the address of the interned string in constant time, so
+/// handles should only be compared with handles of the same [`HexInterner`].
+#[derive(Clone, Debug)]
+pub struct InternedHex<const C: Case>(Arc<str>);
+
+impl<const C: Case> InternedHex<C> {
+  /// Returns the hexadecimal string.
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+}
+
+impl<const C: Case> fmt::Display for InternedHex<C> {
+  /// Formats the [`InternedHex`] the same way as a [`HexString`].
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    crate::fmt_hex_str(&self.0, f)
+  }
+}
+
+impl<const C: Case> PartialEq for InternedHex<C> {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl<const C: Case> Eq for InternedHex<C> {}
+
+impl<const C: Case> Hash for InternedHex<C> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.0.as_ptr().hash(state)
+  }
+}
+
+impl<const C: Case> From<InternedHex<C>> for SharedHexString<C> {
+  fn from(s: InternedHex<C>) -> Self {
+    unsafe { Self::new_unchecked(s.0) }
+  }
+}
+
+impl<const C: Case> From<&InternedHex<C>> for HexString<C> {
+  /// Copies the interned string into a [`HexString`].
+  fn from(s: &InternedHex<C>) -> Self {
+    unsafe { HexString::new_unchecked(s.0.to_string()) }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_deduplicates_equal_hex_str() {
+    let mut interner = HexInterner::<{ Case::Upper }>::new();
+    let a = interner.intern_str("DEAD").unwrap();
+    let b = interner.intern(&UpperHexString::new("DEAD").unwrap());
+    let c = interner.intern_str("BEEF").unwrap();
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(a.as_str().as_ptr(), b.as_str().as_ptr());
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.get("BEEF"), Some(c));
+    assert_eq!(interner.get("CAFE"), None);
+  }
+
+  #[test]
+  fn it_rejects_invalid_str() {
+    let mut interner = HexInterner::<{ Case::Lower }>::default();
+
+    assert_eq!(
+      interner.intern_str("DEAD"),
+      Err(Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 0
+      })
+    );
+    assert!(interner.is_empty());
+  }
+
+  #[test]
+  fn it_converts_into_hex_str() {
+    let mut interner = HexInterner::<{ Case::Lower }>::new();
+    let hex = interner.intern_str("beef").unwrap();
+
+    assert_eq!(hex.to_string(), "beef");
+    assert_eq!(HexString::from(&hex), LowerHexString::new("beef").unwrap());
+    assert_eq!(SharedHexString::from(hex).as_str(), "beef");
+  }
+}
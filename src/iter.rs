@@ -0,0 +1,253 @@
+//! Iteration over the encoded bytes of hexadecimal strings.
+
+use crate::{Case, GenericHexString, HexString};
+
+impl<const C: Case> HexString<C> {
+  /// Returns an iterator over the two-character strings encoding each byte of the [`HexString`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("deadbeef").unwrap();
+  ///
+  /// assert_eq!(hex.byte_pairs().collect::<Vec<_>>(), vec!["de", "ad", "be", "ef"]);
+  /// assert_eq!(hex.byte_pairs().collect::<Vec<_>>().join(":"), "de:ad:be:ef");
+  /// ```
+  pub fn byte_pairs(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator + '_ {
+    self.0.as_bytes().chunks_exact(2).map(|pair| {
+      // SAFETY: the string only contains ASCII characters, so that any pair is a valid string.
+      unsafe { std::str::from_utf8_unchecked(pair) }
+    })
+  }
+
+  /// Returns an iterator over consecutive chunks of `chunk_size` bytes of the [`HexString`], as
+  /// hexadecimal strings borrowing it.
+  ///
+  /// The last bytes are omitted if they do not fill a whole chunk.
+  ///
+  /// # Panics
+  /// This method panics if `chunk_size` is zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("0001020304").unwrap();
+  /// let chunks = hex.chunks_exact_bytes(2).map(|c| c.to_string()).collect::<Vec<_>>();
+  ///
+  /// assert_eq!(chunks, vec!["0001", "0203"]);
+  /// ```
+  pub fn chunks_exact_bytes(
+    &self,
+    chunk_size: usize,
+  ) -> impl DoubleEndedIterator<Item = GenericHexString<C, &str>> + ExactSizeIterator + '_ {
+    assert!(chunk_size != 0, "chunk size must be non-zero");
+
+    self
+      .0
+      .as_bytes()
+      .chunks_exact(chunk_size.saturating_mul(2))
+      .map(sub_hex_string)
+  }
+
+  /// Returns an iterator over all contiguous windows of `size` bytes of the [`HexString`], as
+  /// hexadecimal strings borrowing it.
+  ///
+  /// The iterator yields nothing if the [`HexString`] is shorter than `size` bytes.
+  ///
+  /// # Panics
+  /// This method panics if `size` is zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("000102").unwrap();
+  /// let windows = hex.windows_bytes(2).map(|w| w.to_string()).collect::<Vec<_>>();
+  ///
+  /// assert_eq!(windows, vec!["0001", "0102"]);
+  /// ```
+  pub fn windows_bytes(
+    &self,
+    size: usize,
+  ) -> impl DoubleEndedIterator<Item = GenericHexString<C, &str>> + ExactSizeIterator + '_ {
+    assert!(size != 0, "window size must be non-zero");
+
+    // windows over characters are aligned on bytes by skipping every other one.
+    self
+      .0
+      .as_bytes()
+      .windows(size.saturating_mul(2))
+      .step_by(2)
+      .map(sub_hex_string)
+  }
+
+  /// Returns an iterator over the parts of the [`HexString`] separated by the given byte, as
+  /// hexadecimal strings borrowing it.
+  ///
+  /// It behaves like [`slice::split`] on the decoded bytes, so that empty parts are yielded for
+  /// leading, trailing or consecutive delimiters.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("616200636400").unwrap();
+  /// let parts = hex.split_on_byte(0x00).map(|p| p.to_string()).collect::<Vec<_>>();
+  ///
+  /// assert_eq!(parts, vec!["6162", "6364", ""]);
+  /// ```
+  pub fn split_on_byte(&self, delimiter: u8) -> impl Iterator<Item = GenericHexString<C, &str>> {
+    self.split_on_bytes(&[delimiter])
+  }
+
+  /// Returns an iterator over the parts of the [`HexString`] separated by the given sequence of
+  /// bytes, as hexadecimal strings borrowing it.
+  ///
+  /// Occurrences of the delimiter are matched from the start and never overlap.
+  ///
+  /// # Panics
+  /// This method panics if `delimiter` is empty.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::UpperHexString;
+  ///
+  /// let hex = UpperHexString::new("CAFE01BABECAFE02").unwrap();
+  /// let parts = hex.split_on_bytes(&[0xca, 0xfe]).map(|p| p.to_string()).collect::<Vec<_>>();
+  ///
+  /// assert_eq!(parts, vec!["", "01BABE", "02"]);
+  /// ```
+  pub fn split_on_bytes(
+    &self,
+    delimiter: &[u8],
+  ) -> impl Iterator<Item = GenericHexString<C, &str>> {
+    assert!(!delimiter.is_empty(), "delimiter must be non-empty");
+
+    let delimiter = crate::display(delimiter).to_string();
+    let mut rest = Some(self.0.as_bytes());
+
+    std::iter::from_fn(move || {
+      let s = rest?;
+
+      match find_bytes(s, delimiter.as_bytes()) {
+        Some(i) => {
+          rest = Some(&s[i + delimiter.len()..]);
+          Some(sub_hex_string(&s[..i]))
+        }
+        None => {
+          rest = None;
+          Some(sub_hex_string(s))
+        }
+      }
+    })
+  }
+}
+
+// Returns the position of the first occurrence of `needle` aligned on a byte of `s`, both being
+// hexadecimal strings compared regardless of the case.
+fn find_bytes(s: &[u8], needle: &[u8]) -> Option<usize> {
+  (0..=s.len().saturating_sub(needle.len()))
+    .step_by(2)
+    .find(|&i| s.len() >= i + needle.len() && s[i..i + needle.len()].eq_ignore_ascii_case(needle))
+}
+
+// Constructs a hexadecimal string borrowing whole bytes of a `HexString`.
+fn sub_hex_string<const C: Case>(s: &[u8]) -> GenericHexString<C, &str> {
+  // SAFETY: the slice holds whole bytes of a valid hexadecimal string of the same case.
+  unsafe { GenericHexString::new_unchecked(std::str::from_utf8_unchecked(s)) }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_iterates_over_byte_pairs() {
+    let hex = UpperHexString::new("0A1B2C").unwrap();
+
+    assert_eq!(hex.byte_pairs().collect::<Vec<_>>(), vec!["0A", "1B", "2C"]);
+    assert_eq!(hex.byte_pairs().next_back(), Some("2C"));
+    assert_eq!(hex.byte_pairs().len(), 3);
+    assert_eq!(LowerHexString::default().byte_pairs().next(), None);
+  }
+
+  #[test]
+  fn it_iterates_over_chunks_of_bytes() {
+    let hex = UpperHexString::new("0A1B2C3D4E").unwrap();
+    let chunks = hex.chunks_exact_bytes(2);
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(
+      chunks.map(|c| c.into_storage()).collect::<Vec<_>>(),
+      vec!["0A1B", "2C3D"]
+    );
+    assert_eq!(
+      hex.chunks_exact_bytes(1).next_back().unwrap().as_str(),
+      "4E"
+    );
+    assert_eq!(hex.chunks_exact_bytes(6).next(), None);
+    assert_eq!(hex.chunks_exact_bytes(usize::MAX).next(), None);
+  }
+
+  #[test]
+  fn it_iterates_over_windows_of_bytes() {
+    let hex = LowerHexString::new("0a1b2c3d").unwrap();
+    let windows = hex.windows_bytes(3);
+
+    assert_eq!(windows.len(), 2);
+    assert_eq!(
+      windows.map(|w| w.into_storage()).collect::<Vec<_>>(),
+      vec!["0a1b2c", "1b2c3d"]
+    );
+    assert_eq!(hex.windows_bytes(1).next_back().unwrap().as_str(), "3d");
+    assert_eq!(hex.windows_bytes(4).count(), 1);
+    assert_eq!(hex.windows_bytes(5).next(), None);
+  }
+
+  #[test]
+  fn it_splits_on_bytes() {
+    let hex = UpperHexString::new("00AA0000BB00").unwrap();
+
+    assert_eq!(
+      hex
+        .split_on_byte(0)
+        .map(|p| p.into_storage())
+        .collect::<Vec<_>>(),
+      vec!["", "AA", "", "BB", ""]
+    );
+    assert_eq!(
+      hex
+        .split_on_bytes(&[0xaa, 0x00])
+        .map(|p| p.into_storage())
+        .collect::<Vec<_>>(),
+      vec!["00", "00BB00"]
+    );
+    assert_eq!(
+      hex
+        .split_on_bytes(&[0xa0, 0x00])
+        .map(|p| p.into_storage())
+        .collect::<Vec<_>>(),
+      vec!["00AA0000BB00"]
+    );
+    assert_eq!(
+      LowerHexString::default()
+        .split_on_byte(0)
+        .map(|p| p.into_storage())
+        .collect::<Vec<_>>(),
+      vec![""]
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "chunk size must be non-zero")]
+  fn it_rejects_empty_chunks() {
+    let _ = LowerHexString::default().chunks_exact_bytes(0);
+  }
+}
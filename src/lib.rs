@@ -13,9 +13,107 @@
 //!
 //! The following are a list of [Cargo features][cargo-features] that can be enabled or disabled:
 //! - **serde**: Enable [serde][serde] support.
+//! - **serde_with**: Enable [serde_with][serde_with] adapter to serialize byte containers as
+//!   hexadecimal string. Implies **serde**.
+//! - **borsh**: Enable [borsh][borsh] serialization of hexadecimal string as its decoded bytes.
+//! - **bincode**: Enable native [bincode][bincode] 2 encoding of hexadecimal string as its decoded
+//!   bytes.
+//! - **mac**: Enable MAC address (EUI-48 and EUI-64) support.
+//! - **uuid**: Enable [uuid][uuid] support.
+//! - **derive**: Enable [`HexNewtype`](crate::HexNewtype) derive macro.
+//! - **digest**: Enable [digest][digest] support to hash data into hexadecimal string.
+//! - **generic-array**: Enable [generic-array][generic-array] support.
+//! - **eip55**: Enable [EIP-55][eip55] checksummed Ethereum address support.
+//! - **zeroize**: Enable [zeroize][zeroize] support to wipe hexadecimal string from memory, on drop
+//!   through `into_zeroizing`.
+//! - **secrecy**: Enable [secrecy][secrecy] support to manage hexadecimal secrets. Implies
+//!   **zeroize**.
+//! - **rand**: Enable [rand][rand] support to generate random hexadecimal string.
+//! - **getrandom**: Enable [getrandom][getrandom] support to generate secure random hexadecimal
+//!   string.
+//! - **proptest**: Enable [proptest][proptest] strategies to generate hexadecimal string in
+//!   property tests.
+//! - **quickcheck**: Enable [quickcheck][quickcheck] support to generate hexadecimal string in
+//!   property tests.
+//! - **arbitrary**: Enable [arbitrary][arbitrary] support to generate hexadecimal string from
+//!   fuzzer input.
+//! - **rayon**: Enable [rayon][rayon] support to encode and decode large inputs in parallel.
+//! - **mmap**: Enable streaming decoding of large hexadecimal files through memory mapping.
+//! - **tokio-util**: Enable [tokio-util][tokio-util] codec of newline-delimited hexadecimal frames.
+//! - **futures**: Enable [futures][futures] support to stream decoded bytes asynchronously.
+//! - **defmt**: Enable [defmt][defmt] support to log hexadecimal string from firmware.
+//! - **ufmt**: Enable [ufmt][ufmt] support to format hexadecimal string on microcontrollers.
+//! - **embedded-io**: Enable [embedded-io][embedded-io] support to stream decoded bytes in `no_std`
+//!   environments.
+//! - **wasm**: Enable [wasm-bindgen][wasm-bindgen] bindings to construct and convert hexadecimal
+//!   string from JavaScript.
+//! - **pyo3**: Enable [pyo3][pyo3] conversions to take and return hexadecimal string from Python.
+//! - **ffi**: Enable C foreign function interface to validate and decode hexadecimal string from C
+//!   and C++.
+//! - **valuable**: Enable [valuable][valuable] support to inspect hexadecimal string as structured
+//!   value.
+//! - **tracing**: Enable [tracing][tracing] fields recording hexadecimal string as typed value.
+//!   Implies **valuable**.
+//! - **compact_str**: Enable [compact_str][compact_str] storage of hexadecimal string.
+//! - **arrayvec**: Enable [arrayvec][arrayvec] fixed-capacity storage of hexadecimal string.
+//! - **bumpalo**: Enable [bumpalo][bumpalo] arena allocation of hexadecimal string.
+//! - **sqlx**: Enable [sqlx][sqlx] support to store hexadecimal string as text or binary column.
+//! - **rusqlite**: Enable [rusqlite][rusqlite] support to store hexadecimal string as text or blob.
+//! - **postgres**: Enable [postgres-types][postgres-types] support to store hexadecimal string as
+//!   `TEXT` or `BYTEA` with `tokio-postgres`.
+//! - **bson**: Enable [bson][bson] support to store hexadecimal string in MongoDB. Implies
+//!   **serde**.
+//! - **redis**: Enable [redis][redis] support to use hexadecimal string as Redis key and value.
+//! - **clap**: Enable [clap][clap] value parser to validate hexadecimal command-line arguments.
+//! - **utoipa**: Enable [utoipa][utoipa] support to describe hexadecimal string in OpenAPI
+//!   documents.
+//! - **diesel**: Enable [diesel][diesel] support to store hexadecimal string as `Text` column.
+//! - **diesel-postgres**, **diesel-mysql** and **diesel-sqlite**: Enable storage of hexadecimal
+//!   string as `Binary` column of the given [diesel][diesel] backend. Imply **diesel**.
+//! - **nom** and **winnow**: Enable [nom][nom] and [winnow][winnow] parsers recognizing hexadecimal
+//!   string within grammars.
+//! - **paranoid**: Check the string given to `new_unchecked` in release builds too, as it is
+//!   already checked in debug builds.
 //!
 //! [cargo-features]: https://doc.rust-lang.org/stable/cargo/reference/features.html#the-features-section
 //! [serde]: https://serde.rs
+//! [serde_with]: https://docs.rs/serde_with
+//! [borsh]: https://docs.rs/borsh
+//! [bincode]: https://docs.rs/bincode
+//! [uuid]: https://docs.rs/uuid
+//! [digest]: https://docs.rs/digest
+//! [generic-array]: https://docs.rs/generic-array
+//! [eip55]: https://eips.ethereum.org/EIPS/eip-55
+//! [zeroize]: https://docs.rs/zeroize
+//! [secrecy]: https://docs.rs/secrecy
+//! [rand]: https://docs.rs/rand
+//! [getrandom]: https://docs.rs/getrandom
+//! [proptest]: https://docs.rs/proptest
+//! [quickcheck]: https://docs.rs/quickcheck
+//! [arbitrary]: https://docs.rs/arbitrary
+//! [rayon]: https://docs.rs/rayon
+//! [tokio-util]: https://docs.rs/tokio-util
+//! [futures]: https://docs.rs/futures
+//! [embedded-io]: https://docs.rs/embedded-io
+//! [defmt]: https://docs.rs/defmt
+//! [ufmt]: https://docs.rs/ufmt
+//! [wasm-bindgen]: https://docs.rs/wasm-bindgen
+//! [pyo3]: https://docs.rs/pyo3
+//! [valuable]: https://docs.rs/valuable
+//! [tracing]: https://docs.rs/tracing
+//! [compact_str]: https://docs.rs/compact_str
+//! [arrayvec]: https://docs.rs/arrayvec
+//! [bumpalo]: https://docs.rs/bumpalo
+//! [sqlx]: https://docs.rs/sqlx
+//! [diesel]: https://docs.rs/diesel
+//! [rusqlite]: https://docs.rs/rusqlite
+//! [postgres-types]: https://docs.rs/postgres-types
+//! [bson]: https://docs.rs/bson
+//! [redis]: https://docs.rs/redis
+//! [clap]: https://docs.rs/clap
+//! [utoipa]: https://docs.rs/utoipa
+//! [nom]: https://docs.rs/nom
+//! [winnow]: https://docs.rs/winnow
 
 #![feature(adt_const_params)]
 #![allow(incomplete_features)]
@@ -23,25 +121,379 @@
 
 use std::borrow::Cow;
 use std::convert::{From, TryFrom};
+use std::ffi::{CStr, OsStr};
+use std::fmt::Write;
 use std::marker::ConstParamTy;
-use std::str;
+use std::ops::RangeInclusive;
 use std::str::FromStr;
+use std::{fmt, str};
 
+use ::hex::FromHexError;
 use derive_more::Display;
-use hex::FromHexError;
+
+mod any;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bson")]
+pub mod bson;
+mod builder;
+#[cfg(feature = "bumpalo")]
+mod bumpalo;
+mod case_insensitive;
+#[cfg(feature = "clap")]
+mod clap;
+#[cfg(feature = "tokio-util")]
+mod codec;
+mod compact;
+mod cursor;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "digest")]
+mod digest;
+mod digit;
+mod display;
+#[cfg(feature = "eip55")]
+mod eip55;
+#[cfg(feature = "embedded-io")]
+mod embedded_io;
+mod encode;
+mod env;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fingerprint;
+#[cfg(feature = "generic-array")]
+mod generic_array;
+#[cfg(feature = "getrandom")]
+mod getrandom;
+mod git;
+mod guid;
+mod hex;
+mod hex_like;
+mod inline;
+mod interner;
+mod iter;
+mod lossy;
+#[cfg(feature = "mac")]
+mod mac;
+mod macros;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod nibble;
+mod numeric;
+mod parser;
+#[cfg(any(feature = "nom", feature = "winnow"))]
+pub mod parsers;
+mod pattern;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+mod rayon;
+mod reader;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "secrecy")]
+mod secrecy;
+mod sensitive;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "serde_with")]
+mod serde_with;
+mod shared;
+#[cfg(any(feature = "sqlx", feature = "rusqlite", feature = "redis"))]
+mod sql;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+mod storage;
+#[cfg(feature = "futures")]
+mod stream;
+mod to_hex_string;
+
+pub use any::AnyHexString;
+pub use builder::HexStringBuilder;
+#[cfg(feature = "bumpalo")]
+pub use bumpalo::BumpHexString;
+pub use case_insensitive::CaseInsensitive;
+#[cfg(feature = "clap")]
+pub use clap::HexStringValueParser;
+#[cfg(feature = "tokio-util")]
+pub use codec::HexLineCodec;
+pub use compact::CompactHexString;
+pub use cursor::HexCursor;
+pub use digit::{HexDigit, HexPair};
+pub use display::{display, display_upper, Abbreviated, Grouped, HexDisplay, Wrapped};
+#[cfg(feature = "eip55")]
+pub use eip55::ChecksummedHexString;
+pub use encode::{encode_into, encode_upper_into};
+pub use env::EnvError;
+pub use fingerprint::{Algorithm, Fingerprint, FingerprintError};
+pub use git::{GitOid, GitSha1Oid, GitSha256Oid};
+pub use hex_like::HexLike;
+pub use inline::InlineHexString;
+pub use interner::{HexInterner, InternedHex};
+pub use lossy::LossyReport;
+#[cfg(feature = "mac")]
+pub use mac::{Eui48, Eui64, MacAddr};
+pub use macros::HexTypeError;
+#[cfg(feature = "mmap")]
+pub use mmap::HexFileReader;
+pub use numeric::NumericOrd;
+pub use parser::{DecodedChunk, HexParser};
+pub use pattern::BytePattern;
+#[cfg(feature = "rand")]
+pub use rand::HexDistribution;
+#[cfg(feature = "rayon")]
+pub use rayon::{parallel_threshold, set_parallel_threshold};
+pub use reader::HexReader;
+#[cfg(feature = "secrecy")]
+pub use secrecy::SecretHexString;
+pub use sensitive::Sensitive;
+#[cfg(feature = "serde")]
+pub use serde::{Bounded, Lenient, Numeric};
+#[cfg(feature = "serde_with")]
+pub use serde_with::AsHex;
+pub use shared::SharedHexString;
+#[cfg(any(feature = "sqlx", feature = "rusqlite", feature = "redis"))]
+pub use sql::{AsBlob, AsText};
+pub use storage::{GenericHexString, Storage};
+pub use to_hex_string::ToHexString;
+#[cfg(feature = "wasm")]
+pub use wasm::JsHexString;
+
+/// Derives the conversions, formatting and serde support of a newtype wrapping a hexadecimal
+/// value.
+///
+/// It can be applied to any struct with exactly one field, such as a [`HexString`] or a type
+/// defined by [`define_hex_type`], and generates :
+/// - [`Display`](std::fmt::Display) and [`FromStr`] implementations forwarding to the field
+/// - conversion from and into the field type
+/// - [`AsRef`] implementation to the field type
+/// - [serde](https://serde.rs) support forwarding to the field if the **serde** feature is
+///   enabled
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{HexNewtype, LowerHexString};
+///
+/// #[derive(Debug, HexNewtype, PartialEq)]
+/// struct MyId(LowerHexString);
+///
+/// let id: MyId = "0a0b0c".parse().unwrap();
+///
+/// assert_eq!(id.to_string(), "0a0b0c");
+/// assert_eq!(LowerHexString::from(id), LowerHexString::new("0a0b0c").unwrap());
+/// assert!("0A0B0C".parse::<MyId>().is_err());
+/// ```
+#[cfg(feature = "derive")]
+pub use hexstring_derive::HexNewtype;
+
+#[cfg(feature = "tracing")]
+mod tracing;
+#[cfg(feature = "ufmt")]
+mod ufmt;
+#[cfg(feature = "utoipa")]
+mod utoipa;
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "valuable")]
+mod valuable;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+// Allows derive macros to refer to `::hexstring` within the crate tests.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as hexstring;
+
+// Re-exports used by exported macros only.
+#[doc(hidden)]
+pub mod __private {
+  #[cfg(feature = "serde")]
+  pub use serde;
+  #[cfg(feature = "utoipa")]
+  pub use utoipa;
+
+  #[cfg(feature = "utoipa")]
+  pub use crate::utoipa::hex_schema;
+}
+
+const LOWER_HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const UPPER_HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+const LOWER_HEX_MASK: u8 = 0b01;
+const UPPER_HEX_MASK: u8 = 0b10;
+
+// Lookup table telling for each byte whether it is a valid lowercase and/or uppercase hexadecimal
+// character, according to the above masks.
+static HEX_TABLE: [u8; 256] = {
+  let mut table = [0; 256];
+  let mut i = 0;
+
+  while i < table.len() {
+    table[i] = match i as u8 {
+      b'0'..=b'9' => LOWER_HEX_MASK | UPPER_HEX_MASK,
+      b'a'..=b'f' => LOWER_HEX_MASK,
+      b'A'..=b'F' => UPPER_HEX_MASK,
+      _ => 0,
+    };
+    i += 1;
+  }
+
+  table
+};
 
 /// Errors than can occurs during [`HexString`] construction.
 ///
-/// Refers to [`FromHexError`][hex::FromHexError] for more details.
-pub type Error = FromHexError;
+/// New variants may be added in minor releases, so that matching it requires a wildcard arm.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+  /// Indicates a non-hexadecimal character at the given byte position.
+  #[display(fmt = "Invalid character {:?} at position {}", c, index)]
+  InvalidHexCharacter {
+    /// The invalid character.
+    c: char,
+    /// The byte position of the invalid character.
+    index: usize,
+  },
+  /// Indicates a string with an odd number of characters, as two characters represent one byte.
+  #[display(fmt = "Odd number of digits")]
+  OddLength,
+  /// Indicates a string whose length does not match the expected number of bytes, for instance
+  /// when decoding into a fixed-size array.
+  #[display(fmt = "Invalid string length")]
+  InvalidStringLength,
+  /// Indicates a string whose number of bytes is out of the expected range.
+  #[display(
+    fmt = "Invalid byte length {}, expected between {} and {} bytes",
+    len,
+    min,
+    max
+  )]
+  InvalidByteLength {
+    /// The number of bytes represented by the string.
+    len: usize,
+    /// The minimum number of bytes expected.
+    min: usize,
+    /// The maximum number of bytes expected.
+    max: usize,
+  },
+  /// Indicates a valid hexadecimal character at the given byte position whose case differs from
+  /// the expected one.
+  #[display(
+    fmt = "Invalid case at position {} (expected {:?}, found {:?})",
+    index,
+    expected,
+    found
+  )]
+  InvalidCase {
+    /// The expected case.
+    expected: Case,
+    /// The case of the character.
+    found: Case,
+    /// The byte position of the character.
+    index: usize,
+  },
+}
+
+impl Error {
+  // Shifts the position reported by the error, if any.
+  pub(crate) fn map_index(self, f: impl FnOnce(usize) -> usize) -> Self {
+    match self {
+      Self::InvalidHexCharacter { c, index } => Self::InvalidHexCharacter { c, index: f(index) },
+      Self::InvalidCase {
+        expected,
+        found,
+        index,
+      } => Self::InvalidCase {
+        expected,
+        found,
+        index: f(index),
+      },
+      e => e,
+    }
+  }
+}
+
+impl std::error::Error for Error {}
+
+impl From<FromHexError> for Error {
+  fn from(e: FromHexError) -> Self {
+    match e {
+      FromHexError::InvalidHexCharacter { c, index } => Self::InvalidHexCharacter { c, index },
+      FromHexError::OddLength => Self::OddLength,
+      FromHexError::InvalidStringLength => Self::InvalidStringLength,
+    }
+  }
+}
 
 /// Indicates the case of the hexadecimal string.
-#[derive(Debug, PartialEq, Eq, ConstParamTy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ConstParamTy)]
 pub enum Case {
   /// Indicates a lowercase hexadecimal string.
   Lower,
   /// Indicates a uppercase hexadecimal string.
   Upper,
+  /// Indicates a hexadecimal string of any case, possibly mixing upper and lower characters.
+  Any,
+}
+
+impl Case {
+  /// Detects the case of the given string.
+  ///
+  /// A string without alphabetic characters is considered as lowercase, consistently with the
+  /// encoding of bytes into [`AnyCaseHexString`], whereas a string mixing upper and lower
+  /// characters is considered as [`Case::Any`].
+  ///
+  /// Returns `None` if the string contains non-hexadecimal characters.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::Case;
+  ///
+  /// assert_eq!(Case::detect("deadbeef"), Some(Case::Lower));
+  /// assert_eq!(Case::detect("DEADBEEF"), Some(Case::Upper));
+  /// assert_eq!(Case::detect("DeadBeef"), Some(Case::Any));
+  /// assert_eq!(Case::detect("0123"), Some(Case::Lower));
+  /// assert_eq!(Case::detect("xyz"), None);
+  /// ```
+  pub fn detect(s: &str) -> Option<Case> {
+    let mut has_lower = false;
+    let mut has_upper = false;
+
+    for c in s.chars() {
+      match c {
+        '0'..='9' => {}
+        'a'..='f' => has_lower = true,
+        'A'..='F' => has_upper = true,
+        _ => return None,
+      }
+    }
+
+    Some(match (has_lower, has_upper) {
+      (true, true) => Case::Any,
+      (false, true) => Case::Upper,
+      _ => Case::Lower,
+    })
+  }
 }
 
 /// Provides a structured representation of a hexadecimal string.
@@ -52,7 +504,7 @@ pub enum Case {
 /// - ff04ad992c
 /// - FF04AD99C
 ///
-/// And must not mix upper and lower alphabetic characters.
+/// And must not mix upper and lower alphabetic characters, unless its case is [`Case::Any`].
 ///
 /// # Examples
 ///
@@ -83,17 +535,12 @@ pub enum Case {
 ///
 /// let expected_bytes = [41, 24, 42];
 /// let hex = LowerHexString::from(expected_bytes);
-/// let bytes = Vec::from(hex);
+/// let bytes = hex.decode();
 ///
 /// assert_eq!(expected_bytes, &bytes[..]);
 /// ```
-#[cfg_attr(
-  feature = "serde",
-  derive(serde::Deserialize, serde::Serialize),
-  serde(try_from = "String")
-)]
-#[derive(Clone, Debug, Default, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
-#[display(fmt = "{}", &self.0)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(transparent)]
 pub struct HexString<const C: Case>(Cow<'static, str>);
 
@@ -103,6 +550,9 @@ pub type UpperHexString = HexString<{ Case::Upper }>;
 /// Convenient alias type to represent lowercase hexadecimal string.
 pub type LowerHexString = HexString<{ Case::Lower }>;
 
+/// Convenient alias type to represent hexadecimal string of any case.
+pub type AnyCaseHexString = HexString<{ Case::Any }>;
+
 impl<const C: Case> HexString<C> {
   /// Constructs a new [`HexString`] from a string.
   ///
@@ -111,61 +561,715 @@ impl<const C: Case> HexString<C> {
   pub fn new<S: Into<Cow<'static, str>>>(s: S) -> Result<Self, Error> {
     let s = s.into();
 
+    Self::validate(&s)?;
+
+    Ok(Self(s))
+  }
+
+  /// Checks whether the given string is a valid hexadecimal, reporting every invalid character
+  /// rather than the first one only.
+  ///
+  /// # Errors
+  /// This method returns an [`Error::InvalidHexCharacter`] or an [`Error::InvalidCase`] for each
+  /// invalid character, in order, followed by an [`Error::OddLength`] if the string has an odd
+  /// number of characters.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::{Error, LowerHexString};
+  ///
+  /// assert_eq!(LowerHexString::validate_all("0a0b"), Ok(()));
+  /// assert_eq!(
+  ///   LowerHexString::validate_all("0x0G1"),
+  ///   Err(vec![
+  ///     Error::InvalidHexCharacter { c: 'x', index: 1 },
+  ///     Error::InvalidHexCharacter { c: 'G', index: 3 },
+  ///     Error::OddLength,
+  ///   ])
+  /// );
+  /// ```
+  pub fn validate_all(s: &str) -> Result<(), Vec<Error>> {
+    let mut errors = s
+      .char_indices()
+      .filter(|&(_, c)| !Self::is_hex_char(c))
+      .map(|(index, c)| Self::invalid_char(c, index))
+      .collect::<Vec<_>>();
+
     if s.len() & 1 != 0 {
-      return Err(Error::OddLength);
+      errors.push(Error::OddLength);
     }
 
-    if let Some((index, c)) = s.chars().enumerate().find(|(_, c)| match C {
-      Case::Lower => !matches!(c, '0'..='9' | 'a'..='f'),
-      Case::Upper => !matches!(c, '0'..='9' | 'A'..='F'),
-    }) {
-      return Err(Error::InvalidHexCharacter { c, index });
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
     }
+  }
+
+  /// Constructs a new [`HexString`] from a string of any case, normalizing it to the expected
+  /// case.
+  ///
+  /// This method performs a copy only if the internal string is a string literal whose case
+  /// differs from the expected one.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal, regardless of the case.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::from_mixed("DeadBeef").unwrap();
+  ///
+  /// assert_eq!(hex, LowerHexString::new("deadbeef").unwrap());
+  /// ```
+  pub fn from_mixed<S: Into<Cow<'static, str>>>(s: S) -> Result<Self, Error> {
+    Ok(AnyCaseHexString::new(s)?.to_case())
+  }
+
+  /// Constructs a new [`HexString`] from a string representing exactly `len` bytes.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal or if it does not represent
+  /// exactly `len` bytes.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::{Error, LowerHexString};
+  ///
+  /// assert!(LowerHexString::new_exact_len("0a0b", 2).is_ok());
+  /// assert_eq!(
+  ///   LowerHexString::new_exact_len("0a0b0c", 2),
+  ///   Err(Error::InvalidByteLength { len: 3, min: 2, max: 2 })
+  /// );
+  /// ```
+  pub fn new_exact_len<S: Into<Cow<'static, str>>>(s: S, len: usize) -> Result<Self, Error> {
+    Self::new_with_len_range(s, len..=len)
+  }
+
+  /// Constructs a new [`HexString`] from a non-empty string.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal or if it is empty.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// assert!(LowerHexString::new("").is_ok());
+  /// assert!(LowerHexString::new_non_empty("").is_err());
+  /// ```
+  pub fn new_non_empty<S: Into<Cow<'static, str>>>(s: S) -> Result<Self, Error> {
+    Self::new_with_len_range(s, 1..=usize::MAX)
+  }
+
+  /// Constructs a new [`HexString`] from a string representing a number of bytes within the given
+  /// range.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal or if its number of bytes is
+  /// out of the range.
+  pub fn new_with_len_range<S: Into<Cow<'static, str>>>(
+    s: S,
+    range: RangeInclusive<usize>,
+  ) -> Result<Self, Error> {
+    let s = s.into();
+    let len = s.len() / 2;
+
+    // checks the length before validating the characters so that oversized strings are rejected
+    // early.
+    if s.len() & 1 == 0 && !range.contains(&len) {
+      return Err(Error::InvalidByteLength {
+        len,
+        min: *range.start(),
+        max: *range.end(),
+      });
+    }
+
+    Self::new(s)
+  }
+
+  /// Constructs a new [`HexString`] from an OS string, such as a command-line argument.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal, including when it is not
+  /// valid Unicode.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::ffi::OsStr;
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::try_from_os_str(OsStr::new("0a0b")).unwrap();
+  ///
+  /// assert_eq!(hex, LowerHexString::new("0a0b").unwrap());
+  /// ```
+  pub fn try_from_os_str(s: &OsStr) -> Result<Self, Error> {
+    let bytes = s.as_encoded_bytes();
+
+    Self::validate_ascii(bytes)?;
+
+    // SAFETY: the bytes are all hexadecimal characters, which are valid UTF-8.
+    Ok(Self(Cow::Owned(
+      unsafe { str::from_utf8_unchecked(bytes) }.to_owned(),
+    )))
+  }
+
+  /// Constructs a new [`HexString`] from a nul-terminated C string, such as a string received over
+  /// FFI.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::ffi::CStr;
+  /// use hexstring::UpperHexString;
+  ///
+  /// let s = c"0A0B";
+  ///
+  /// assert_eq!(
+  ///   UpperHexString::try_from_c_str(s).unwrap(),
+  ///   UpperHexString::new("0A0B").unwrap()
+  /// );
+  /// ```
+  pub fn try_from_c_str(s: &CStr) -> Result<Self, Error> {
+    Self::from_ascii(s.to_bytes().to_vec())
+  }
+
+  /// Constructs a new [`HexString`] from the ASCII characters of a hexadecimal string, such as the
+  /// hexadecimal text received from a network protocol.
+  ///
+  /// Unlike the conversion from `&[u8]` which encodes the given bytes, this method interprets them
+  /// as hexadecimal characters. The given buffer is reused without any copy.
+  ///
+  /// # Errors
+  /// This method fails if the given bytes are not the characters of a valid hexadecimal string.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::from_ascii(b"0a0b".to_vec()).unwrap();
+  ///
+  /// assert_eq!(hex, LowerHexString::new("0a0b").unwrap());
+  /// assert_eq!(hex, LowerHexString::from_ascii(&b"0a0b"[..]).unwrap());
+  /// assert_ne!(hex, LowerHexString::from(&b"0a0b"[..]));
+  /// ```
+  pub fn from_ascii<B: Into<Cow<'static, [u8]>>>(bytes: B) -> Result<Self, Error> {
+    let bytes = bytes.into();
+
+    Self::validate_ascii(&bytes)?;
+
+    // SAFETY: the bytes are all hexadecimal characters, which are valid UTF-8.
+    let s = unsafe {
+      match bytes {
+        Cow::Borrowed(bytes) => Cow::Borrowed(str::from_utf8_unchecked(bytes)),
+        Cow::Owned(bytes) => Cow::Owned(String::from_utf8_unchecked(bytes)),
+      }
+    };
 
     Ok(Self(s))
   }
 
+  /// Returns the case of the [`HexString`].
+  ///
+  /// The case is detected from the string for an [`AnyCaseHexString`], refers to [`Case::detect`]
+  /// for more details.
+  pub fn case(&self) -> Case {
+    match C {
+      // `Case::detect` only fails if the `HexString` has been constructed from `new_unchecked`
+      // method with an invalid string.
+      Case::Any => Case::detect(&self.0).unwrap_or(Case::Any),
+      case => case,
+    }
+  }
+
+  /// Constructs a [`HexString`] of another case from a [`HexString`].
+  ///
+  /// This method does nothing if both cases are the same, if the target case is [`Case::Any`] or
+  /// if the string has no character to convert. Otherwise, it performs a copy if the internal
+  /// string is a string literal.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #![allow(incomplete_features)]
+  /// # #![feature(adt_const_params)]
+  /// use hexstring::{Case, HexString, LowerHexString, UpperHexString};
+  ///
+  /// fn normalize<const C: Case>(hex: HexString<C>) -> LowerHexString {
+  ///   hex.to_case()
+  /// }
+  ///
+  /// assert_eq!(
+  ///   normalize(UpperHexString::new("ABCDEF").unwrap()),
+  ///   LowerHexString::new("abcdef").unwrap()
+  /// );
+  /// ```
+  pub fn to_case<const C2: Case>(self) -> HexString<C2> {
+    let mut s = self.into_cow();
+
+    if C != C2 {
+      match C2 {
+        Case::Lower if s.bytes().any(|b| b.is_ascii_uppercase()) => {
+          s.to_mut().make_ascii_lowercase()
+        }
+        Case::Upper if s.bytes().any(|b| b.is_ascii_lowercase()) => {
+          s.to_mut().make_ascii_uppercase()
+        }
+        _ => {}
+      }
+    }
+
+    HexString(s)
+  }
+
+  /// Returns the lowercase representation of the [`HexString`], borrowing it if there is no
+  /// character to convert.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::borrow::Cow;
+  /// use hexstring::UpperHexString;
+  ///
+  /// let hex = UpperHexString::new("0123").unwrap();
+  ///
+  /// assert!(matches!(hex.as_lowercase(), Cow::Borrowed("0123")));
+  ///
+  /// let hex = UpperHexString::new("ABCD").unwrap();
+  ///
+  /// assert_eq!(hex.as_lowercase(), "abcd");
+  /// ```
+  pub fn as_lowercase(&self) -> Cow<'_, str> {
+    if C != Case::Lower && self.0.bytes().any(|b| b.is_ascii_uppercase()) {
+      Cow::Owned(self.0.to_ascii_lowercase())
+    } else {
+      Cow::Borrowed(&self.0)
+    }
+  }
+
+  /// Returns the uppercase representation of the [`HexString`], borrowing it if there is no
+  /// character to convert.
+  pub fn as_uppercase(&self) -> Cow<'_, str> {
+    if C != Case::Upper && self.0.bytes().any(|b| b.is_ascii_lowercase()) {
+      Cow::Owned(self.0.to_ascii_uppercase())
+    } else {
+      Cow::Borrowed(&self.0)
+    }
+  }
+
+  /// Checks whether two [`HexString`] represent the same bytes, regardless of their case.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::{AnyCaseHexString, LowerHexString};
+  ///
+  /// let lowercase_hex = LowerHexString::new("abcdef").unwrap();
+  /// let any_case_hex = AnyCaseHexString::new("AbCdEf").unwrap();
+  ///
+  /// assert!(lowercase_hex.eq_ignore_case(&any_case_hex));
+  /// ```
+  pub fn eq_ignore_case<const C2: Case>(&self, other: &HexString<C2>) -> bool {
+    self.0.eq_ignore_ascii_case(&other.0)
+  }
+
+  /// Decodes the [`HexString`] into bytes.
+  ///
+  /// This method never panics: a [`HexString`] always holds a valid hexadecimal string, unless it
+  /// has been constructed from an invalid string through the `new_unchecked` method, in which case
+  /// the content of the bytes is unspecified.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("2a1a02").unwrap();
+  ///
+  /// assert_eq!(hex.decode(), [42, 26, 2]);
+  /// ```
+  pub fn decode(&self) -> Vec<u8> {
+    let mut bytes = vec![0u8; self.0.len() / 2];
+
+    // the error can only be caused by a misuse of `new_unchecked`.
+    let _ = decode_to_slice(&self.0, &mut bytes);
+
+    bytes
+  }
+
+  /// Decodes the [`HexString`] into bytes, reusing the buffer of the internal string if it is
+  /// owned.
+  ///
+  /// Like [`HexString::decode`], this method never panics and the content of the bytes is
+  /// unspecified if the [`HexString`] has been constructed from an invalid string through the
+  /// `new_unchecked` method.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::UpperHexString;
+  ///
+  /// let hex = UpperHexString::new(String::from("2A1A02")).unwrap();
+  ///
+  /// assert_eq!(hex.into_bytes(), [42, 26, 2]);
+  /// ```
+  pub fn into_bytes(self) -> Vec<u8> {
+    match self.into_cow() {
+      Cow::Borrowed(s) => HexString::<C>(Cow::Borrowed(s)).decode(),
+      Cow::Owned(s) => {
+        let mut buffer = s.into_bytes();
+        let len = buffer.len() / 2;
+
+        // each byte is written before the characters of the next ones, so decoding in place never
+        // overwrites a character which has not been read yet.
+        for i in 0..len {
+          buffer[i] = decode_nibble(buffer[2 * i]) << 4 | decode_nibble(buffer[2 * i + 1]);
+        }
+
+        buffer.truncate(len);
+        buffer
+      }
+    }
+  }
+
+  /// Decodes the byte at the given position, or returns `None` if it is out of bounds.
+  ///
+  /// Only the two characters of the byte are decoded, allowing random access into large values.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("2a1a02").unwrap();
+  ///
+  /// assert_eq!(hex.byte_at(1), Some(26));
+  /// assert_eq!(hex.byte_at(3), None);
+  /// ```
+  pub fn byte_at(&self, index: usize) -> Option<u8> {
+    let start = index.checked_mul(2)?;
+
+    match self.0.as_bytes().get(start..start.checked_add(2)?)? {
+      &[high, low] => Some(decode_nibble(high) << 4 | decode_nibble(low)),
+      _ => None,
+    }
+  }
+
+  /// Constructs a new [`HexString`] by repeating the [`HexString`] `n` times.
+  ///
+  /// The resulting string is allocated with its exact length.
+  ///
+  /// # Panics
+  /// This method panics if the resulting length overflows.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("dead").unwrap();
+  ///
+  /// assert_eq!(hex.repeat(3), LowerHexString::new("deaddeaddead").unwrap());
+  /// ```
+  pub fn repeat(&self, n: usize) -> Self {
+    Self(Cow::Owned(self.0.repeat(n)))
+  }
+
+  /// Constructs a new [`HexString`] made of the given byte repeated `n` times.
+  ///
+  /// The resulting string is allocated with its exact length.
+  ///
+  /// # Panics
+  /// This method panics if the resulting length overflows.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::UpperHexString;
+  ///
+  /// assert_eq!(
+  ///   UpperHexString::repeated_byte(0xff, 4),
+  ///   UpperHexString::new("FFFFFFFF").unwrap()
+  /// );
+  /// ```
+  pub fn repeated_byte(byte: u8, n: usize) -> Self {
+    let mut pair = String::with_capacity(2);
+
+    Self::encode_into(&[byte], &mut pair);
+
+    Self(Cow::Owned(pair.repeat(n)))
+  }
+
+  /// Converts the [`HexString`] into a [`HexString`] of another case without any copy, if its
+  /// characters are valid for that case.
+  ///
+  /// # Errors
+  /// This method gives back the [`HexString`] if its characters are not valid for the given case.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::{AnyCaseHexString, Case, LowerHexString, UpperHexString};
+  ///
+  /// let hex = AnyCaseHexString::new("deadbeef").unwrap();
+  /// let hex = hex.try_into_case::<{ Case::Upper }>().unwrap_err();
+  /// let hex: LowerHexString = hex.try_into_case().unwrap();
+  /// ```
+  pub fn try_into_case<const C2: Case>(self) -> Result<HexString<C2>, Self> {
+    if self.0.chars().all(HexString::<C2>::is_hex_char) {
+      Ok(HexString(self.into_cow()))
+    } else {
+      Err(self)
+    }
+  }
+
+  /// Appends the hexadecimal representation of the given bytes to a string, in the expected case.
+  ///
+  /// This method allows to reuse the same buffer across several encodings, whereas bytes of
+  /// [`AnyCaseHexString`] are encoded in lowercase.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::UpperHexString;
+  ///
+  /// let mut s = String::from("0x");
+  ///
+  /// UpperHexString::encode_into(&[42, 255], &mut s);
+  ///
+  /// assert_eq!(s, "0x2AFF");
+  /// ```
+  pub fn encode_into(bytes: &[u8], s: &mut String) {
+    let digits = match C {
+      Case::Upper => UPPER_HEX_DIGITS,
+      Case::Lower | Case::Any => LOWER_HEX_DIGITS,
+    };
+
+    let start = s.len();
+
+    // SAFETY: the string is only extended with null and then hexadecimal characters, which are
+    // all valid UTF-8.
+    let buffer = unsafe { s.as_mut_vec() };
+
+    buffer.resize(start + bytes.len() * 2, 0);
+    encode_to_slice(bytes, &mut buffer[start..], digits);
+  }
+
+  // Moves the underlying string out of the `HexString`.
+  pub(crate) fn into_cow(mut self) -> Cow<'static, str> {
+    std::mem::take(&mut self.0)
+  }
+
+  // Checks whether the given string is a valid hexadecimal string of the expected case.
+  pub(crate) fn validate(s: &str) -> Result<(), Error> {
+    if s.len() & 1 != 0 {
+      return Err(Error::OddLength);
+    }
+
+    if let Some(index) = s.bytes().position(|b| !Self::is_hex_byte(b)) {
+      // all the previous bytes are ASCII so the index lies on a character boundary.
+      let c = s[index..].chars().next().unwrap();
+
+      return Err(Self::invalid_char(c, index));
+    }
+
+    Ok(())
+  }
+
+  // Checks whether the given bytes are the characters of a valid hexadecimal string of the
+  // expected case.
+  pub(crate) fn validate_ascii(bytes: &[u8]) -> Result<(), Error> {
+    if bytes.len() & 1 != 0 {
+      return Err(Error::OddLength);
+    }
+
+    if let Some(index) = bytes.iter().position(|&b| !Self::is_hex_byte(b)) {
+      // the character may span several bytes if not ASCII.
+      let c = String::from_utf8_lossy(&bytes[index..bytes.len().min(index + 4)])
+        .chars()
+        .next()
+        .unwrap();
+
+      return Err(Self::invalid_char(c, index));
+    }
+
+    Ok(())
+  }
+
+  // Returns the error reported for an invalid character, distinguishing the hexadecimal characters
+  // of the opposite case.
+  pub(crate) fn invalid_char(c: char, index: usize) -> Error {
+    if c.is_ascii_hexdigit() {
+      Error::InvalidCase {
+        expected: C,
+        found: if c.is_ascii_lowercase() {
+          Case::Lower
+        } else {
+          Case::Upper
+        },
+        index,
+      }
+    } else {
+      Error::InvalidHexCharacter { c, index }
+    }
+  }
+
+  /// Checks whether the given character is a valid hexadecimal character of the expected case.
+  pub(crate) fn is_hex_char(c: char) -> bool {
+    c.is_ascii() && Self::is_hex_byte(c as u8)
+  }
+
+  /// Checks whether the given byte is a valid hexadecimal character of the expected case.
+  pub(crate) fn is_hex_byte(b: u8) -> bool {
+    let mask = match C {
+      Case::Lower => LOWER_HEX_MASK,
+      Case::Upper => UPPER_HEX_MASK,
+      Case::Any => LOWER_HEX_MASK | UPPER_HEX_MASK,
+    };
+
+    HEX_TABLE[b as usize] & mask != 0
+  }
+
   /// Creates a new [`HexString`] without checking the string.
   ///
+  /// The string is still checked in debug builds, or in all builds if the **paranoid** feature is
+  /// enabled, so that an invalid string is caught where it is created.
+  ///
   /// # Safety
   /// The string should be a valid hexadecimal string.
+  ///
+  /// # Panics
+  /// This method panics if the string is not a valid hexadecimal string, in debug builds or if the
+  /// **paranoid** feature is enabled.
   pub unsafe fn new_unchecked<S: Into<Cow<'static, str>>>(s: S) -> Self {
-    Self(s.into())
+    let s = s.into();
+
+    Self::check_unchecked(&s);
+
+    Self(s)
+  }
+
+  // Panics if the string given to an unchecked constructor is invalid, in debug builds or if the
+  // **paranoid** feature is enabled.
+  #[inline]
+  #[track_caller]
+  pub(crate) fn check_unchecked(s: &str) {
+    if cfg!(any(debug_assertions, feature = "paranoid")) {
+      if let Err(e) = Self::validate(s) {
+        panic!("Invalid hexadecimal string given to `new_unchecked`: {}", e);
+      }
+    }
   }
 }
 
 impl LowerHexString {
   /// Constructs an [`UpperHexString`] from a [`LowerHexString`].
   ///
-  /// This method performs a copy if the internal string is a string literal.
+  /// This method performs a copy if the internal string is a string literal with alphabetic
+  /// characters.
   pub fn to_uppercase(self) -> UpperHexString {
-    let mut s = self.0.into_owned();
-
-    s.make_ascii_uppercase();
+    self.to_case()
+  }
 
-    unsafe { UpperHexString::new_unchecked(s) }
+  /// Returns the uppercase representation of the [`LowerHexString`] without consuming it.
+  ///
+  /// Refers to [`HexString::as_uppercase`] for more details.
+  pub fn as_opposite_case(&self) -> Cow<'_, str> {
+    self.as_uppercase()
   }
 }
 
 impl UpperHexString {
   /// Constructs a [`LowerHexString`] from an [`UpperHexString`].
   ///
-  /// This method performs a copy if the internal string is a string literal.
+  /// This method performs a copy if the internal string is a string literal with alphabetic
+  /// characters.
+  pub fn to_lowercase(self) -> LowerHexString {
+    self.to_case()
+  }
+
+  /// Returns the lowercase representation of the [`UpperHexString`] without consuming it.
+  ///
+  /// Refers to [`HexString::as_lowercase`] for more details.
+  pub fn as_opposite_case(&self) -> Cow<'_, str> {
+    self.as_lowercase()
+  }
+}
+
+impl AnyCaseHexString {
+  /// Constructs a [`LowerHexString`] from an [`AnyCaseHexString`].
+  ///
+  /// This method performs a copy if the internal string is a string literal with uppercase
+  /// characters.
   pub fn to_lowercase(self) -> LowerHexString {
-    let mut s = self.0.into_owned();
+    self.to_case()
+  }
 
-    s.make_ascii_lowercase();
+  /// Constructs an [`UpperHexString`] from an [`AnyCaseHexString`].
+  ///
+  /// This method performs a copy if the internal string is a string literal with lowercase
+  /// characters.
+  pub fn to_uppercase(self) -> UpperHexString {
+    self.to_case()
+  }
+}
 
-    unsafe { LowerHexString::new_unchecked(s) }
+impl<const C: Case> fmt::Display for HexString<C> {
+  /// Formats the [`HexString`] the same way as [`str`], respecting width, fill, alignment and
+  /// precision.
+  ///
+  /// Unlike [`str`], the `0` flag pads the string with leading zeros, such as `{:08}`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt_hex_str(&self.0, f)
+  }
+}
+
+// Formats a hexadecimal string the same way as `str`, except that the `0` flag pads it with
+// leading zeros.
+pub(crate) fn fmt_hex_str(s: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+  if !f.sign_aware_zero_pad() {
+    return f.pad(s);
+  }
+
+  let s = f
+    .precision()
+    .and_then(|precision| s.get(..precision))
+    .unwrap_or(s);
+
+  for _ in s.len()..f.width().unwrap_or(0) {
+    f.write_char('0')?;
+  }
+
+  f.write_str(s)
+}
+
+impl PartialEq<UpperHexString> for LowerHexString {
+  fn eq(&self, other: &UpperHexString) -> bool {
+    self.eq_ignore_case(other)
+  }
+}
+
+impl PartialEq<LowerHexString> for UpperHexString {
+  fn eq(&self, other: &LowerHexString) -> bool {
+    self.eq_ignore_case(other)
   }
 }
 
 impl<const C: Case> From<&[u8]> for HexString<C> {
   fn from(bytes: &[u8]) -> Self {
-    let s = match C {
-      Case::Upper => hex::encode_upper(bytes),
-      Case::Lower => hex::encode(bytes),
-    };
+    let mut s = String::with_capacity(bytes.len() * 2);
+
+    Self::encode_into(bytes, &mut s);
 
     unsafe { Self::new_unchecked(s) }
   }
@@ -183,14 +1287,19 @@ impl<const C: Case, const N: usize> From<[u8; N]> for HexString<C> {
   }
 }
 
-impl<const C: Case> From<HexString<C>> for Vec<u8> {
-  fn from(s: HexString<C>) -> Self {
-    // since `HexString` always represents a valid hexadecimal string, the result of `hex::decode`
-    // can be safely unwrapped.
-    //
-    // Note that this call may panic if the `HexString` has been constructed from `new_unchecked`
-    // method.
-    hex::decode(s.0.as_ref()).unwrap()
+impl<const C: Case> TryFrom<HexString<C>> for Vec<u8> {
+  type Error = Error;
+
+  /// Decodes the [`HexString`] into bytes.
+  ///
+  /// Unlike [`HexString::decode`], this conversion reports an invalid string constructed from the
+  /// `new_unchecked` method.
+  fn try_from(s: HexString<C>) -> Result<Self, Self::Error> {
+    let mut bytes = vec![0u8; s.0.len() / 2];
+
+    decode_to_slice(&s.0, &mut bytes)?;
+
+    Ok(bytes)
   }
 }
 
@@ -208,26 +1317,48 @@ impl<const C: Case, const N: usize> TryFrom<HexString<C>> for [u8; N] {
   fn try_from(s: HexString<C>) -> Result<Self, Self::Error> {
     let mut bytes = [0u8; N];
 
-    hex::decode_to_slice(s.0.as_ref(), &mut bytes).map(|_| bytes)
+    ::hex::decode_to_slice(s.0.as_ref(), &mut bytes)?;
+
+    Ok(bytes)
   }
 }
 
-// Hide `std::convert::TryFrom` conversion implementation from string used only by
-// `serde::Deserialize` mechanism.
-//
-// It constraints user to use [`HexString::new`] to construct a hexadecimal string.
-#[cfg(feature = "serde")]
-mod seal {
-  use super::*;
-  use std::convert::TryFrom;
+// Encodes the bytes into a slice of twice their length, in parallel for large inputs if the
+// **rayon** feature is enabled.
+fn encode_to_slice(bytes: &[u8], out: &mut [u8], digits: &[u8; 16]) {
+  #[cfg(feature = "rayon")]
+  if bytes.len() >= rayon::parallel_threshold() {
+    return rayon::encode_to_slice(bytes, out, digits);
+  }
 
-  #[doc(hidden)]
-  impl<const C: Case> TryFrom<String> for HexString<C> {
-    type Error = Error;
+  encode_chunk(bytes, out, digits)
+}
 
-    fn try_from(s: String) -> Result<Self, Self::Error> {
-      Self::new(s)
-    }
+pub(crate) fn encode_chunk(bytes: &[u8], out: &mut [u8], digits: &[u8; 16]) {
+  for (&b, pair) in bytes.iter().zip(out.chunks_exact_mut(2)) {
+    pair[0] = digits[(b >> 4) as usize];
+    pair[1] = digits[(b & 0x0f) as usize];
+  }
+}
+
+// Decodes the string into a slice of half its length, in parallel for large inputs if the
+// **rayon** feature is enabled.
+fn decode_to_slice(s: &str, out: &mut [u8]) -> Result<(), Error> {
+  #[cfg(feature = "rayon")]
+  if out.len() >= rayon::parallel_threshold() {
+    return rayon::decode_to_slice(s, out);
+  }
+
+  Ok(::hex::decode_to_slice(s, out)?)
+}
+
+// Returns the value of a hexadecimal character, or zero if the character is invalid.
+fn decode_nibble(c: u8) -> u8 {
+  match c {
+    b'0'..=b'9' => c - b'0',
+    b'a'..=b'f' => c - b'a' + 10,
+    b'A'..=b'F' => c - b'A' + 10,
+    _ => 0,
   }
 }
 
@@ -285,6 +1416,77 @@ mod tests {
     );
   }
 
+  #[test]
+  fn it_encodes_bytes_with_exact_capacity() {
+    let bytes = (0..=255).collect::<Vec<u8>>();
+    let hex = LowerHexString::from(&bytes[..]);
+
+    assert_eq!(hex.0, ::hex::encode(&bytes));
+    assert_eq!(hex.0.len(), 512);
+    assert_eq!(
+      UpperHexString::from(&bytes[..]).0,
+      ::hex::encode_upper(&bytes)
+    );
+
+    match hex.0 {
+      Cow::Owned(ref s) => assert_eq!(s.capacity(), 512),
+      Cow::Borrowed(_) => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn it_encodes_bytes_into_str() {
+    let mut s = String::new();
+
+    LowerHexString::encode_into(&[222, 173], &mut s);
+    UpperHexString::encode_into(&[190, 239], &mut s);
+    AnyCaseHexString::encode_into(&[], &mut s);
+    AnyCaseHexString::encode_into(&[10], &mut s);
+
+    assert_eq!(s, "deadBEEF0a");
+  }
+
+  #[test]
+  fn it_constructs_from_any_case_str() {
+    assert_eq!(
+      AnyCaseHexString::new("DeadBeef"),
+      Ok(HexString(Cow::Borrowed("DeadBeef")))
+    );
+    assert_eq!(AnyCaseHexString::new("abc"), Err(Error::OddLength));
+    assert_eq!(
+      AnyCaseHexString::new("DeadBeeZ"),
+      Err(Error::InvalidHexCharacter { c: 'Z', index: 7 })
+    );
+    assert_eq!(
+      AnyCaseHexString::from([42, 15, 5]),
+      HexString::<{ Case::Any }>(Cow::Borrowed("2a0f05"))
+    );
+  }
+
+  #[test]
+  fn it_constructs_from_mixed_str() {
+    assert_eq!(
+      LowerHexString::from_mixed("DeadBeef"),
+      Ok(HexString(Cow::Owned("deadbeef".to_string())))
+    );
+    assert_eq!(
+      UpperHexString::from_mixed("DeadBeef".to_string()),
+      Ok(HexString(Cow::Owned("DEADBEEF".to_string())))
+    );
+    assert!(matches!(
+      LowerHexString::from_mixed("deadbeef"),
+      Ok(HexString(Cow::Borrowed("deadbeef")))
+    ));
+    assert!(matches!(
+      UpperHexString::from_mixed("0123"),
+      Ok(HexString(Cow::Borrowed("0123")))
+    ));
+    assert_eq!(
+      LowerHexString::from_mixed("DeadBeeZ"),
+      Err(Error::InvalidHexCharacter { c: 'Z', index: 7 })
+    );
+  }
+
   #[test]
   fn it_rejects_str_with_odd_length() {
     assert_eq!(LowerHexString::new("abc"), Err(Error::OddLength));
@@ -301,34 +1503,287 @@ mod tests {
       UpperHexString::new("ABVCD109"),
       Err(Error::InvalidHexCharacter { c: 'V', index: 2 })
     );
+    assert_eq!(
+      AnyCaseHexString::new("0aé00"),
+      Err(Error::InvalidHexCharacter { c: 'é', index: 2 })
+    );
+    assert_eq!(
+      LowerHexString::new("0a\u{0}0"),
+      Err(Error::InvalidHexCharacter {
+        c: '\u{0}',
+        index: 2
+      })
+    );
+  }
+
+  #[test]
+  fn it_reports_all_invalid_chars() {
+    assert_eq!(UpperHexString::validate_all(""), Ok(()));
+    assert_eq!(AnyCaseHexString::validate_all("0aB1"), Ok(()));
+    assert_eq!(
+      UpperHexString::validate_all("é0a1B"),
+      Err(vec![
+        Error::InvalidHexCharacter { c: 'é', index: 0 },
+        Error::InvalidCase {
+          expected: Case::Upper,
+          found: Case::Lower,
+          index: 3
+        },
+      ])
+    );
+    assert_eq!(
+      LowerHexString::validate_all("0a0"),
+      Err(vec![Error::OddLength])
+    );
+  }
+
+  #[test]
+  fn it_constructs_from_str_with_exact_len() {
+    assert_eq!(
+      LowerHexString::new_exact_len("0a0b", 2),
+      Ok(HexString(Cow::Borrowed("0a0b")))
+    );
+    assert_eq!(
+      UpperHexString::new_exact_len("0A0B0C", 2),
+      Err(Error::InvalidByteLength {
+        len: 3,
+        min: 2,
+        max: 2
+      })
+    );
+    assert_eq!(
+      LowerHexString::new_exact_len("0a0", 2),
+      Err(Error::OddLength)
+    );
+    assert_eq!(
+      LowerHexString::new_exact_len("0Z0b", 2),
+      Err(Error::InvalidHexCharacter { c: 'Z', index: 1 })
+    );
+  }
+
+  #[test]
+  fn it_constructs_from_str_with_len_range() {
+    assert!(LowerHexString::new_with_len_range("0a", 1..=3).is_ok());
+    assert!(LowerHexString::new_with_len_range("0a0b0c", 1..=3).is_ok());
+    assert_eq!(
+      LowerHexString::new_with_len_range("", 1..=3),
+      Err(Error::InvalidByteLength {
+        len: 0,
+        min: 1,
+        max: 3
+      })
+    );
+    assert_eq!(
+      UpperHexString::new_with_len_range("0A0B0C0D", 1..=3),
+      Err(Error::InvalidByteLength {
+        len: 4,
+        min: 1,
+        max: 3
+      })
+    );
+  }
+
+  #[test]
+  fn it_constructs_from_non_empty_str() {
+    assert_eq!(
+      LowerHexString::new_non_empty("0a"),
+      Ok(HexString(Cow::Borrowed("0a")))
+    );
+    assert_eq!(
+      UpperHexString::new_non_empty(""),
+      Err(Error::InvalidByteLength {
+        len: 0,
+        min: 1,
+        max: usize::MAX
+      })
+    );
+  }
+
+  #[test]
+  fn it_constructs_from_os_str() {
+    assert_eq!(
+      AnyCaseHexString::try_from_os_str(OsStr::new("0a0B")),
+      Ok(HexString(Cow::Borrowed("0a0B")))
+    );
+    assert_eq!(
+      LowerHexString::try_from_os_str(OsStr::new("0a0B")),
+      Err(Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 3
+      })
+    );
+
+    #[cfg(unix)]
+    {
+      use std::os::unix::ffi::OsStrExt;
+
+      assert_eq!(
+        LowerHexString::try_from_os_str(OsStr::from_bytes(b"0a\xff0")),
+        Err(Error::InvalidHexCharacter {
+          c: char::REPLACEMENT_CHARACTER,
+          index: 2
+        })
+      );
+      assert_eq!(
+        UpperHexString::try_from_os_str(OsStr::from_bytes("0Aé\u{ff}".as_bytes())),
+        Err(Error::InvalidHexCharacter { c: 'é', index: 2 })
+      );
+      assert_eq!(
+        UpperHexString::try_from_os_str(OsStr::from_bytes(b"\xff\xfe0A")),
+        Err(Error::InvalidHexCharacter {
+          c: char::REPLACEMENT_CHARACTER,
+          index: 0
+        })
+      );
+    }
+  }
+
+  #[test]
+  fn it_constructs_from_c_str() {
+    let s = c"0A0B";
+
+    assert_eq!(
+      UpperHexString::try_from_c_str(s),
+      Ok(HexString(Cow::Borrowed("0A0B")))
+    );
+    assert_eq!(
+      LowerHexString::try_from_c_str(s),
+      Err(Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 1
+      })
+    );
+    assert_eq!(
+      LowerHexString::try_from_c_str(c"0a\xff0"),
+      Err(Error::InvalidHexCharacter {
+        c: char::REPLACEMENT_CHARACTER,
+        index: 2
+      })
+    );
+    assert_eq!(
+      LowerHexString::try_from_c_str(c"0a0"),
+      Err(Error::OddLength)
+    );
+  }
+
+  #[test]
+  fn it_constructs_from_ascii() {
+    let bytes = b"0a0b".to_vec();
+    let ptr = bytes.as_ptr();
+    let hex = LowerHexString::from_ascii(bytes).unwrap();
+
+    assert_eq!(hex.0.as_ptr(), ptr);
+    assert_eq!(
+      UpperHexString::from_ascii(&b"0A0B"[..]),
+      Ok(HexString(Cow::Borrowed("0A0B")))
+    );
+    assert_eq!(
+      UpperHexString::from_ascii(&b"0a0B"[..]),
+      Err(Error::InvalidCase {
+        expected: Case::Upper,
+        found: Case::Lower,
+        index: 1
+      })
+    );
+    assert_eq!(
+      AnyCaseHexString::from_ascii(&b"0a0"[..]),
+      Err(Error::OddLength)
+    );
   }
 
   #[test]
   fn it_constructs_from_unchecked_str() {
     let hex = unsafe { LowerHexString::new_unchecked("0a0b0c0d0e") };
-    let bytes = Vec::from(hex);
 
-    assert_eq!(&bytes[..], [10, 11, 12, 13, 14]);
+    assert_eq!(hex.decode(), [10, 11, 12, 13, 14]);
   }
 
   #[test]
-  #[should_panic]
   fn it_fails_to_convert_into_bytes_from_invalid_unchecked_str() {
-    let hex = unsafe { LowerHexString::new_unchecked("thisisnotvalid") };
-    let _ = Vec::from(hex);
+    // bypasses the check of `new_unchecked` in debug builds.
+    let hex: LowerHexString = HexString(Cow::Borrowed("thisisnotvalid"));
+
+    assert_eq!(hex.decode().len(), 7);
+    assert_eq!(
+      Vec::try_from(hex),
+      Err(Error::InvalidHexCharacter { c: 't', index: 0 })
+    );
+
+    let hex: LowerHexString = HexString(Cow::Borrowed("0a0"));
+
+    assert_eq!(hex.decode().len(), 1);
+    assert_eq!(Vec::try_from(hex), Err(Error::OddLength));
+  }
+
+  #[test]
+  #[cfg(any(debug_assertions, feature = "paranoid"))]
+  #[should_panic(expected = "Invalid hexadecimal string given to `new_unchecked`")]
+  fn it_checks_unchecked_str() {
+    let _ = unsafe { UpperHexString::new_unchecked("0a0b") };
   }
 
   #[test]
   fn it_converts_into_bytes() {
     let hex = LowerHexString::new("2a1a02").unwrap();
-    let bytes = Vec::from(hex);
 
-    assert_eq!(&bytes[..], [42, 26, 2]);
+    assert_eq!(hex.decode(), [42, 26, 2]);
+    assert_eq!(Vec::try_from(hex), Ok(vec![42, 26, 2]));
 
     let hex = UpperHexString::new("2A1A02").unwrap();
-    let bytes = Vec::from(hex);
 
-    assert_eq!(&bytes[..], [42, 26, 2]);
+    assert_eq!(hex.decode(), [42, 26, 2]);
+    assert_eq!(Vec::try_from(hex), Ok(vec![42, 26, 2]));
+  }
+
+  #[test]
+  fn it_decodes_byte_at_position() {
+    let hex = AnyCaseHexString::new("00fF7a").unwrap();
+
+    assert_eq!(hex.byte_at(0), Some(0x00));
+    assert_eq!(hex.byte_at(1), Some(0xff));
+    assert_eq!(hex.byte_at(2), Some(0x7a));
+    assert_eq!(hex.byte_at(3), None);
+    assert_eq!(hex.byte_at(usize::MAX), None);
+    assert_eq!(LowerHexString::default().byte_at(0), None);
+  }
+
+  #[test]
+  fn it_repeats_hex_str() {
+    let hex = UpperHexString::new("0A1B").unwrap();
+    let repeated = hex.repeat(3);
+
+    assert_eq!(repeated, UpperHexString::new("0A1B0A1B0A1B").unwrap());
+    assert_eq!(repeated.as_str().len(), 12);
+    assert_eq!(hex.repeat(0), UpperHexString::default());
+    assert_eq!(
+      LowerHexString::repeated_byte(0xab, 3),
+      LowerHexString::new("ababab").unwrap()
+    );
+    assert_eq!(
+      AnyCaseHexString::repeated_byte(0x0f, 2),
+      AnyCaseHexString::new("0f0f").unwrap()
+    );
+    assert_eq!(
+      UpperHexString::repeated_byte(0xff, 0),
+      UpperHexString::default()
+    );
+  }
+
+  #[test]
+  fn it_converts_into_bytes_reusing_buffer() {
+    let s = String::from("00ff7F80aB");
+    let ptr = s.as_ptr();
+    let bytes = AnyCaseHexString::new(s).unwrap().into_bytes();
+
+    assert_eq!(bytes, [0x00, 0xff, 0x7f, 0x80, 0xab]);
+    assert_eq!(bytes.as_ptr(), ptr);
+    assert_eq!(
+      LowerHexString::new("2a1a02").unwrap().into_bytes(),
+      [42, 26, 2]
+    );
+    assert!(LowerHexString::default().into_bytes().is_empty());
   }
 
   #[test]
@@ -375,6 +1830,181 @@ mod tests {
     assert_eq!(hex, expected_hex);
   }
 
+  #[test]
+  fn it_creates_strict_hex_str_from_any_case_str() {
+    let hex = AnyCaseHexString::new("DeadBeef").unwrap();
+
+    assert_eq!(
+      hex.clone().to_lowercase(),
+      LowerHexString::new("deadbeef").unwrap()
+    );
+    assert_eq!(hex.to_uppercase(), UpperHexString::new("DEADBEEF").unwrap());
+  }
+
+  #[test]
+  fn it_converts_to_any_case() {
+    let hex = LowerHexString::new("abcdef").unwrap();
+
+    assert!(matches!(
+      hex.clone().to_case::<{ Case::Lower }>(),
+      HexString(Cow::Borrowed("abcdef"))
+    ));
+    assert!(matches!(
+      hex.clone().to_case::<{ Case::Any }>(),
+      HexString(Cow::Borrowed("abcdef"))
+    ));
+    assert_eq!(
+      hex.to_case::<{ Case::Upper }>(),
+      UpperHexString::new("ABCDEF").unwrap()
+    );
+    assert_eq!(
+      AnyCaseHexString::new("AbCdEf")
+        .unwrap()
+        .to_case::<{ Case::Lower }>(),
+      LowerHexString::new("abcdef").unwrap()
+    );
+  }
+
+  #[test]
+  fn it_converts_digit_only_str_without_copy() {
+    assert!(matches!(
+      LowerHexString::new("0123").unwrap().to_uppercase(),
+      HexString(Cow::Borrowed("0123"))
+    ));
+    assert!(matches!(
+      AnyCaseHexString::new("4567").unwrap().to_lowercase(),
+      HexString(Cow::Borrowed("4567"))
+    ));
+    assert!(matches!(
+      LowerHexString::from_mixed("89"),
+      Ok(HexString(Cow::Borrowed("89")))
+    ));
+  }
+
+  #[test]
+  fn it_borrows_opposite_case_without_conversion() {
+    let lowercase_hex = LowerHexString::new("0a1b").unwrap();
+    let uppercase_hex = UpperHexString::new("0A1B").unwrap();
+    let any_case_hex = AnyCaseHexString::new("0a1B").unwrap();
+
+    assert_eq!(lowercase_hex.as_opposite_case(), "0A1B");
+    assert_eq!(uppercase_hex.as_opposite_case(), "0a1b");
+    assert_eq!(any_case_hex.as_lowercase(), "0a1b");
+    assert_eq!(any_case_hex.as_uppercase(), "0A1B");
+    assert!(matches!(
+      lowercase_hex.as_lowercase(),
+      Cow::Borrowed("0a1b")
+    ));
+    assert!(matches!(
+      UpperHexString::new("0123").unwrap().as_opposite_case(),
+      Cow::Borrowed("0123")
+    ));
+  }
+
+  #[test]
+  fn it_compares_regardless_of_case() {
+    let lowercase_hex = LowerHexString::new("0a1b").unwrap();
+    let uppercase_hex = UpperHexString::new("0A1B").unwrap();
+
+    assert_eq!(lowercase_hex, uppercase_hex);
+    assert_eq!(uppercase_hex, lowercase_hex);
+    assert_ne!(lowercase_hex, UpperHexString::new("0A1C").unwrap());
+    assert!(lowercase_hex.eq_ignore_case(&AnyCaseHexString::new("0a1B").unwrap()));
+    assert!(!lowercase_hex.eq_ignore_case(&LowerHexString::new("0a1b0c").unwrap()));
+  }
+
+  #[test]
+  fn it_formats_with_flags() {
+    let hex = LowerHexString::new("0a1b2c").unwrap();
+
+    assert_eq!(format!("{}", hex), "0a1b2c");
+    assert_eq!(format!("{:>8}", hex), "  0a1b2c");
+    assert_eq!(format!("{:<8}|", hex), "0a1b2c  |");
+    assert_eq!(format!("{:*^10}", hex), "**0a1b2c**");
+    assert_eq!(format!("{:.4}", hex), "0a1b");
+    assert_eq!(format!("{:>6.2}", hex), "    0a");
+    assert_eq!(format!("{:08}", hex), "000a1b2c");
+    assert_eq!(format!("{:08.4}", hex), "00000a1b");
+    assert_eq!(format!("{:04}", hex), "0a1b2c");
+  }
+
+  #[test]
+  fn it_detects_case() {
+    assert_eq!(Case::detect(""), Some(Case::Lower));
+    assert_eq!(Case::detect("0a1b"), Some(Case::Lower));
+    assert_eq!(Case::detect("0A1B"), Some(Case::Upper));
+    assert_eq!(Case::detect("0a1B"), Some(Case::Any));
+    assert_eq!(Case::detect("0a1g"), None);
+
+    assert_eq!(LowerHexString::new("0a").unwrap().case(), Case::Lower);
+    assert_eq!(UpperHexString::new("01").unwrap().case(), Case::Upper);
+    assert_eq!(AnyCaseHexString::new("0A").unwrap().case(), Case::Upper);
+    assert_eq!(AnyCaseHexString::new("aA").unwrap().case(), Case::Any);
+  }
+
+  #[test]
+  fn it_converts_into_matching_case() {
+    let hex = AnyCaseHexString::new("0A1B").unwrap();
+    let hex = hex.try_into_case::<{ Case::Lower }>().unwrap_err();
+
+    assert_eq!(
+      hex.try_into_case::<{ Case::Upper }>(),
+      Ok(UpperHexString::new("0A1B").unwrap())
+    );
+    assert_eq!(
+      UpperHexString::new("0123")
+        .unwrap()
+        .try_into_case::<{ Case::Lower }>(),
+      Ok(LowerHexString::new("0123").unwrap())
+    );
+  }
+
+  #[cfg(feature = "derive")]
+  mod derive {
+    use super::*;
+
+    #[derive(Debug, HexNewtype, PartialEq)]
+    struct Id(UpperHexString);
+
+    #[derive(Debug, HexNewtype, PartialEq)]
+    struct Named<const C: Case> {
+      value: HexString<C>,
+    }
+
+    #[test]
+    fn it_forwards_to_inner_hex_str() {
+      let id = Id::from(UpperHexString::new("0A0B").unwrap());
+
+      assert_eq!(id.to_string(), "0A0B");
+      assert_eq!("0A0B".parse::<Id>(), Ok(id));
+      assert_eq!(
+        "0a0b".parse::<Id>(),
+        Err(Error::InvalidCase {
+          expected: Case::Upper,
+          found: Case::Lower,
+          index: 1
+        })
+      );
+
+      let named = "0a0b".parse::<Named<{ Case::Lower }>>().unwrap();
+
+      assert_eq!(named.as_ref(), &LowerHexString::new("0a0b").unwrap());
+      assert_eq!(
+        LowerHexString::from(named),
+        LowerHexString::new("0a0b").unwrap()
+      );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_forwards_serde_to_inner_hex_str() {
+      let id: Id = serde_json::from_str("\"0A0B\"").unwrap();
+
+      assert_eq!(serde_json::to_string(&id).unwrap(), "\"0A0B\"");
+      assert!(serde_json::from_str::<Id>("\"0a0b\"").is_err());
+    }
+  }
+
   #[cfg(feature = "serde")]
   mod serde {
     use super::*;
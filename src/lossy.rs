@@ -0,0 +1,184 @@
+//! Sanitizing construction of hexadecimal string from sloppy input.
+
+use crate::{Case, Error, HexString};
+
+/// Report of the changes made by [`HexString::from_lossy`] to the given string.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LossyReport {
+  removed: Vec<(usize, char)>,
+  normalized: usize,
+}
+
+impl LossyReport {
+  /// Returns the removed characters along with their byte position in the given string,
+  /// including the characters of a `0x` prefix.
+  pub fn removed(&self) -> &[(usize, char)] {
+    &self.removed
+  }
+
+  /// Returns the number of characters whose case has been normalized.
+  pub fn normalized(&self) -> usize {
+    self.normalized
+  }
+
+  /// Checks whether the given string was already a valid hexadecimal string of the expected case.
+  pub fn is_clean(&self) -> bool {
+    self.removed.is_empty() && self.normalized == 0
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Constructs a new [`HexString`] from a string after removing a `0x` prefix and any
+  /// non-hexadecimal character, such as whitespaces or separators, and normalizing the case.
+  ///
+  /// It is meant to ingest human input, such as a key pasted over several lines, while the
+  /// returned [`LossyReport`] describes what has been changed.
+  ///
+  /// # Errors
+  /// This method fails if the remaining characters are odd in number.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let (hex, report) = LowerHexString::from_lossy("0xDE:AD be\nEF").unwrap();
+  ///
+  /// assert_eq!(hex, LowerHexString::new("deadbeef").unwrap());
+  /// assert_eq!(report.removed().len(), 5);
+  /// assert_eq!(report.normalized(), 6);
+  /// ```
+  pub fn from_lossy(s: &str) -> Result<(Self, LossyReport), Error> {
+    let mut report = LossyReport::default();
+    let mut hex = String::with_capacity(s.len());
+    let (prefix_len, rest) = match s.get(..2) {
+      Some("0x" | "0X") => (2, &s[2..]),
+      _ => (0, s),
+    };
+
+    report.removed.extend(s[..prefix_len].char_indices());
+
+    for (index, c) in rest.char_indices() {
+      if !c.is_ascii_hexdigit() {
+        report.removed.push((prefix_len + index, c));
+        continue;
+      }
+
+      let normalized = match C {
+        Case::Lower => c.to_ascii_lowercase(),
+        Case::Upper => c.to_ascii_uppercase(),
+        Case::Any => c,
+      };
+
+      if normalized != c {
+        report.normalized += 1;
+      }
+
+      hex.push(normalized);
+    }
+
+    if hex.len() & 1 != 0 {
+      return Err(Error::OddLength);
+    }
+
+    // SAFETY: the string only contains hexadecimal characters of the expected case.
+    Ok((unsafe { Self::new_unchecked(hex) }, report))
+  }
+
+  /// Suggests a normalized [`HexString`] for a string rejected by [`HexString::new`], allowing to
+  /// offer a "did you mean" fix.
+  ///
+  /// The suggestion is the string without `0x` prefix and non-hexadecimal characters, in the
+  /// expected case. It is `None` if the string is already valid or if no hexadecimal string can be
+  /// made out of it.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let input = "0xDEADBEEF";
+  ///
+  /// let error = LowerHexString::new(input).unwrap_err();
+  /// let suggestion = LowerHexString::suggestion(input).unwrap();
+  ///
+  /// assert_eq!(
+  ///   format!("{}, did you mean {}?", error, suggestion),
+  ///   "Invalid character 'x' at position 1, did you mean deadbeef?"
+  /// );
+  ///
+  /// assert_eq!(LowerHexString::suggestion("deadbeef"), None);
+  /// assert_eq!(LowerHexString::suggestion("DEADBEE"), None);
+  /// ```
+  pub fn suggestion(s: &str) -> Option<Self> {
+    match Self::from_lossy(s) {
+      Ok((hex, report)) if !report.is_clean() && !hex.0.is_empty() => Some(hex),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{AnyCaseHexString, UpperHexString};
+
+  #[test]
+  fn it_constructs_from_sloppy_str() {
+    let (hex, report) = UpperHexString::from_lossy("0x0a 1b-2C\r\n").unwrap();
+
+    assert_eq!(hex, UpperHexString::new("0A1B2C").unwrap());
+    assert_eq!(
+      report.removed(),
+      &[
+        (0, '0'),
+        (1, 'x'),
+        (4, ' '),
+        (7, '-'),
+        (10, '\r'),
+        (11, '\n')
+      ]
+    );
+    assert_eq!(report.normalized(), 2);
+    assert!(!report.is_clean());
+
+    let (hex, report) = AnyCaseHexString::from_lossy("0a→Bc").unwrap();
+
+    assert_eq!(hex, AnyCaseHexString::new("0aBc").unwrap());
+    assert_eq!(report.removed(), &[(2, '→')]);
+    assert_eq!(report.normalized(), 0);
+  }
+
+  #[test]
+  fn it_reports_clean_str() {
+    let (hex, report) = UpperHexString::from_lossy("0A").unwrap();
+
+    assert_eq!(hex, UpperHexString::new("0A").unwrap());
+    assert!(report.is_clean());
+    assert_eq!(
+      UpperHexString::from_lossy("").unwrap().1,
+      LossyReport::default()
+    );
+  }
+
+  #[test]
+  fn it_suggests_normalized_str() {
+    assert_eq!(
+      UpperHexString::suggestion("0xdead beef"),
+      Some(UpperHexString::new("DEADBEEF").unwrap())
+    );
+    assert_eq!(
+      AnyCaseHexString::suggestion("de-AD"),
+      Some(AnyCaseHexString::new("deAD").unwrap())
+    );
+    assert_eq!(UpperHexString::suggestion("DEAD"), None);
+    assert_eq!(UpperHexString::suggestion("0x"), None);
+    assert_eq!(UpperHexString::suggestion("xyz"), None);
+    assert_eq!(UpperHexString::suggestion("dea"), None);
+  }
+
+  #[test]
+  fn it_rejects_odd_number_of_hex_chars() {
+    assert_eq!(UpperHexString::from_lossy("0x0a 1"), Err(Error::OddLength));
+  }
+}
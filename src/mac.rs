@@ -0,0 +1,260 @@
+//! MAC address (EUI-48 and EUI-64) interoperability.
+
+use std::convert::{From, TryFrom};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Case, Error, HexString};
+
+/// Provides a structured representation of a MAC address made of `N` bytes.
+///
+/// Use [`Eui48`] or [`Eui64`] type aliases rather than the raw [`MacAddr`] type.
+///
+/// # Examples
+///
+/// A [`MacAddr`] can be parsed from its colon, hyphen, dot (Cisco) or bare hexadecimal notation,
+/// regardless of the case.
+///
+/// ```
+/// use hexstring::Eui48;
+///
+/// let mac: Eui48 = "00:1A:2b:3c:4d:5e".parse().unwrap();
+///
+/// assert_eq!(mac, "001a.2b3c.4d5e".parse().unwrap());
+/// assert_eq!(mac.to_string(), "00:1a:2b:3c:4d:5e");
+/// assert_eq!(format!("{:X}", mac), "00:1A:2B:3C:4D:5E");
+/// assert_eq!(mac.to_cisco_string(), "001a.2b3c.4d5e");
+/// ```
+///
+/// It also converts from and into [`HexString`].
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use hexstring::{Eui48, LowerHexString};
+///
+/// let hex = LowerHexString::new("001a2b3c4d5e").unwrap();
+/// let mac = Eui48::try_from(hex.clone()).unwrap();
+///
+/// assert_eq!(LowerHexString::from(mac), hex);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct MacAddr<const N: usize>([u8; N]);
+
+/// Convenient alias type to represent a 48-bit MAC address.
+pub type Eui48 = MacAddr<6>;
+
+/// Convenient alias type to represent a 64-bit MAC address.
+pub type Eui64 = MacAddr<8>;
+
+impl<const N: usize> MacAddr<N> {
+  /// Constructs a new [`MacAddr`] from an array of bytes.
+  pub const fn new(bytes: [u8; N]) -> Self {
+    Self(bytes)
+  }
+
+  /// Returns the underlying bytes of the [`MacAddr`].
+  pub const fn as_bytes(&self) -> &[u8; N] {
+    &self.0
+  }
+
+  /// Formats the [`MacAddr`] using the lowercase dot (Cisco) notation, such as `001a.2b3c.4d5e`.
+  pub fn to_cisco_string(&self) -> String {
+    let mut s = String::with_capacity(N * 2 + N / 2);
+
+    for (i, chunk) in self.0.chunks(2).enumerate() {
+      if i != 0 {
+        s.push('.');
+      }
+
+      s.push_str(&hex::encode(chunk));
+    }
+
+    s
+  }
+
+  fn fmt_with_case(&self, f: &mut fmt::Formatter<'_>, case: Case) -> fmt::Result {
+    for (i, byte) in self.0.iter().enumerate() {
+      if i != 0 {
+        f.write_str(":")?;
+      }
+
+      match case {
+        Case::Upper => write!(f, "{:02X}", byte)?,
+        Case::Lower | Case::Any => write!(f, "{:02x}", byte)?,
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl<const N: usize> fmt::Display for MacAddr<N> {
+  /// Formats the [`MacAddr`] using the canonical lowercase colon notation, such as
+  /// `00:1a:2b:3c:4d:5e`.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.fmt_with_case(f, Case::Lower)
+  }
+}
+
+impl<const N: usize> fmt::LowerHex for MacAddr<N> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.fmt_with_case(f, Case::Lower)
+  }
+}
+
+impl<const N: usize> fmt::UpperHex for MacAddr<N> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.fmt_with_case(f, Case::Upper)
+  }
+}
+
+impl<const N: usize> FromStr for MacAddr<N> {
+  type Err = Error;
+
+  /// Parses a [`MacAddr`] from one of the following notations :
+  /// - colon, such as `00:1a:2b:3c:4d:5e`
+  /// - hyphen, such as `00-1a-2b-3c-4d-5e`
+  /// - dot (Cisco), such as `001a.2b3c.4d5e`
+  /// - bare, such as `001a2b3c4d5e`
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    // the group length is the number of hexadecimal digits between two separators.
+    let (group_len, separator) = match s.find(|c: char| !c.is_ascii_hexdigit()) {
+      None => (N * 2, None),
+      Some(index) => (index, s[index..].chars().next()),
+    };
+
+    let expected_len = match (group_len, separator) {
+      (_, None) => N * 2,
+      (2, Some(':' | '-')) => N * 3 - 1,
+      (4, Some('.')) if N % 2 == 0 => N * 2 + N / 2 - 1,
+      (_, Some(c)) => {
+        return Err(Error::InvalidHexCharacter {
+          c,
+          index: group_len,
+        })
+      }
+    };
+
+    if s.len() != expected_len {
+      return Err(Error::InvalidStringLength);
+    }
+
+    let mut bytes = [0u8; N];
+    let mut nibbles = 0;
+
+    for (index, c) in s.char_indices() {
+      if (index + 1) % (group_len + 1) == 0 {
+        if Some(c) != separator {
+          return Err(Error::InvalidHexCharacter { c, index });
+        }
+      } else {
+        let nibble = c
+          .to_digit(16)
+          .ok_or(Error::InvalidHexCharacter { c, index })? as u8;
+
+        bytes[nibbles / 2] = bytes[nibbles / 2] << 4 | nibble;
+        nibbles += 1;
+      }
+    }
+
+    Ok(Self(bytes))
+  }
+}
+
+impl<const N: usize> From<[u8; N]> for MacAddr<N> {
+  fn from(bytes: [u8; N]) -> Self {
+    Self(bytes)
+  }
+}
+
+impl<const N: usize> From<MacAddr<N>> for [u8; N] {
+  fn from(mac: MacAddr<N>) -> Self {
+    mac.0
+  }
+}
+
+impl<const C: Case, const N: usize> From<MacAddr<N>> for HexString<C> {
+  fn from(mac: MacAddr<N>) -> Self {
+    Self::from(mac.0)
+  }
+}
+
+impl<const C: Case, const N: usize> TryFrom<HexString<C>> for MacAddr<N> {
+  type Error = Error;
+
+  fn try_from(s: HexString<C>) -> Result<Self, Self::Error> {
+    <[u8; N]>::try_from(s).map(Self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  const BYTES: [u8; 6] = [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e];
+
+  #[test]
+  fn it_parses_all_notations() {
+    for s in [
+      "00:1a:2b:3c:4d:5e",
+      "00:1A:2B:3C:4D:5E",
+      "00-1a-2b-3c-4d-5e",
+      "001a.2b3c.4d5e",
+      "001a2b3c4d5e",
+    ] {
+      assert_eq!(s.parse::<Eui48>(), Ok(MacAddr(BYTES)));
+    }
+
+    assert_eq!(
+      "00:1a:2b:3c:4d:5e:6f:70".parse::<Eui64>(),
+      Ok(MacAddr([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e, 0x6f, 0x70]))
+    );
+  }
+
+  #[test]
+  fn it_rejects_invalid_notations() {
+    assert_eq!(
+      "00:1a:2b:3c:4d".parse::<Eui48>(),
+      Err(Error::InvalidStringLength)
+    );
+    assert_eq!(
+      "00:1a-2b:3c:4d:5e".parse::<Eui48>(),
+      Err(Error::InvalidHexCharacter { c: '-', index: 5 })
+    );
+    assert_eq!(
+      "00:1a:2b:3c:4d:5g".parse::<Eui48>(),
+      Err(Error::InvalidHexCharacter { c: 'g', index: 16 })
+    );
+    assert_eq!(
+      "001.a2b3c4d5e".parse::<Eui48>(),
+      Err(Error::InvalidHexCharacter { c: '.', index: 3 })
+    );
+  }
+
+  #[test]
+  fn it_formats_all_notations() {
+    let mac = MacAddr(BYTES);
+
+    assert_eq!(mac.to_string(), "00:1a:2b:3c:4d:5e");
+    assert_eq!(format!("{:x}", mac), "00:1a:2b:3c:4d:5e");
+    assert_eq!(format!("{:X}", mac), "00:1A:2B:3C:4D:5E");
+    assert_eq!(mac.to_cisco_string(), "001a.2b3c.4d5e");
+  }
+
+  #[test]
+  fn it_converts_from_and_into_hex_str() {
+    let mac = Eui48::try_from(UpperHexString::new("001A2B3C4D5E").unwrap()).unwrap();
+
+    assert_eq!(mac, MacAddr(BYTES));
+    assert_eq!(
+      LowerHexString::from(mac),
+      LowerHexString::new("001a2b3c4d5e").unwrap()
+    );
+    assert_eq!(
+      Eui64::try_from(LowerHexString::new("001a2b3c4d5e").unwrap()),
+      Err(Error::InvalidStringLength)
+    );
+  }
+}
@@ -0,0 +1,340 @@
+//! Declarative macros to define domain-specific hexadecimal types.
+
+use std::fmt;
+
+use crate::Error;
+
+/// Errors than can occurs during the construction of a type defined by [`define_hex_type`].
+///
+/// It wraps an [`Error`] along with the name of the type being constructed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HexTypeError {
+  type_name: &'static str,
+  error: Error,
+}
+
+impl HexTypeError {
+  /// Constructs a new [`HexTypeError`] from the name of the type and the underlying error.
+  pub fn new(type_name: &'static str, error: Error) -> Self {
+    Self { type_name, error }
+  }
+
+  /// Returns the name of the type being constructed.
+  pub fn type_name(&self) -> &'static str {
+    self.type_name
+  }
+
+  /// Returns the underlying error.
+  pub fn error(&self) -> &Error {
+    &self.error
+  }
+}
+
+impl fmt::Display for HexTypeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Invalid {}: {}", self.type_name, self.error)
+  }
+}
+
+impl std::error::Error for HexTypeError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    Some(&self.error)
+  }
+}
+
+/// Defines a fixed-length hexadecimal newtype.
+///
+/// The generated type holds exactly `N` bytes and is constructed from a hexadecimal string of the
+/// given [`Case`](crate::Case). It provides :
+/// - `new` constructor and [`FromStr`](std::str::FromStr) implementation
+/// - [`Display`](std::fmt::Display) implementation
+/// - conversion from and into `[u8; N]`
+/// - conversion from and into [`HexString`](crate::HexString)
+/// - [serde](https://serde.rs) support if the **serde** feature is enabled
+/// - [utoipa](https://docs.rs/utoipa) schema if the **utoipa** feature is enabled
+///
+/// Errors are reported with [`HexTypeError`] which mentions the name of the type.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::define_hex_type;
+///
+/// define_hex_type!(
+///   /// Hash of a transaction.
+///   pub TxHash, 32, Lower
+/// );
+///
+/// let hash: TxHash = "8f0c8ba6fa2e6a3fc5d2dffb3c8b2e7a2cd7a1ee01e2ab9aa8f7a3b9c1d2e3f4"
+///   .parse()
+///   .unwrap();
+/// let bytes: [u8; 32] = hash.into();
+///
+/// assert_eq!(TxHash::from(bytes), hash);
+///
+/// let error = "8f0c".parse::<TxHash>().unwrap_err();
+///
+/// assert_eq!(
+///   error.to_string(),
+///   "Invalid TxHash: Invalid byte length 2, expected between 32 and 32 bytes"
+/// );
+/// ```
+#[macro_export]
+macro_rules! define_hex_type {
+  ($(#[$meta:meta])* $vis:vis $name:ident, $len:expr, $case:ident $(,)?) => {
+    $(#[$meta])*
+    #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    #[repr(transparent)]
+    $vis struct $name([u8; $len]);
+
+    #[allow(dead_code)]
+    impl $name {
+      #[doc = concat!("Number of bytes of [`", stringify!($name), "`].")]
+      pub const BYTE_LEN: usize = $len;
+
+      #[doc = concat!("Constructs a new [`", stringify!($name), "`] from a string.")]
+      ///
+      /// # Errors
+      /// This method fails if the given string is not a valid hexadecimal of the expected case and
+      /// length.
+      pub fn new<S>(s: S) -> ::std::result::Result<Self, $crate::HexTypeError>
+      where
+        S: ::std::convert::Into<::std::borrow::Cow<'static, str>>,
+      {
+        $crate::HexString::<{ $crate::Case::$case }>::new(s)
+          .map_err(|e| $crate::HexTypeError::new(stringify!($name), e))
+          .and_then(<Self as ::std::convert::TryFrom<_>>::try_from)
+      }
+
+      #[doc = concat!("Constructs a new [`", stringify!($name), "`] from an array of bytes.")]
+      pub const fn from_bytes(bytes: [u8; $len]) -> Self {
+        Self(bytes)
+      }
+
+      #[doc = concat!("Returns the underlying bytes of the [`", stringify!($name), "`].")]
+      pub const fn as_bytes(&self) -> &[u8; $len] {
+        &self.0
+      }
+
+      #[doc = concat!("Constructs a `HexString` from the [`", stringify!($name), "`].")]
+      pub fn to_hex_string(self) -> $crate::HexString<{ $crate::Case::$case }> {
+        $crate::HexString::from(self.0)
+      }
+    }
+
+    impl ::std::fmt::Display for $name {
+      fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::std::fmt::Display::fmt(&self.to_hex_string(), f)
+      }
+    }
+
+    impl ::std::str::FromStr for $name {
+      type Err = $crate::HexTypeError;
+
+      fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+        Self::new(s.to_owned())
+      }
+    }
+
+    impl ::std::convert::From<[u8; $len]> for $name {
+      fn from(bytes: [u8; $len]) -> Self {
+        Self(bytes)
+      }
+    }
+
+    impl ::std::convert::From<$name> for [u8; $len] {
+      fn from(value: $name) -> Self {
+        value.0
+      }
+    }
+
+    impl ::std::convert::From<$name> for $crate::HexString<{ $crate::Case::$case }> {
+      fn from(value: $name) -> Self {
+        value.to_hex_string()
+      }
+    }
+
+    impl ::std::convert::TryFrom<$crate::HexString<{ $crate::Case::$case }>> for $name {
+      type Error = $crate::HexTypeError;
+
+      fn try_from(
+        s: $crate::HexString<{ $crate::Case::$case }>,
+      ) -> ::std::result::Result<Self, Self::Error> {
+        let len = $crate::HexLike::byte_len(&s);
+
+        <[u8; $len] as ::std::convert::TryFrom<_>>::try_from(s)
+          .map(Self)
+          .map_err(|e| match e {
+            $crate::Error::InvalidStringLength => $crate::Error::InvalidByteLength {
+              len,
+              min: $len,
+              max: $len,
+            },
+            e => e,
+          })
+          .map_err(|e| $crate::HexTypeError::new(stringify!($name), e))
+      }
+    }
+
+    $crate::__cfg_serde! {
+      impl $crate::__private::serde::Serialize for $name {
+        fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+        where
+          S: $crate::__private::serde::Serializer,
+        {
+          $crate::__private::serde::Serialize::serialize(&self.to_hex_string(), serializer)
+        }
+      }
+
+      impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+        fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+        where
+          D: $crate::__private::serde::Deserializer<'de>,
+        {
+          let s = <::std::string::String as $crate::__private::serde::Deserialize>::deserialize(
+            deserializer,
+          )?;
+
+          Self::new(s).map_err(<D::Error as $crate::__private::serde::de::Error>::custom)
+        }
+      }
+    }
+
+    $crate::__cfg_utoipa! {
+      impl $crate::__private::utoipa::PartialSchema for $name {
+        fn schema() -> $crate::__private::utoipa::openapi::RefOr<
+          $crate::__private::utoipa::openapi::schema::Schema,
+        > {
+          $crate::__private::hex_schema($crate::Case::$case, Some($len * 2), Some($len * 2))
+        }
+      }
+
+      impl $crate::__private::utoipa::ToSchema for $name {}
+    }
+  };
+}
+
+// Expands the given items only if the **serde** feature is enabled, since `cfg` attributes within
+// exported macros are evaluated against the features of the calling crate.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_serde {
+  ($($item:item)*) => {
+    $($item)*
+  };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_serde {
+  ($($item:item)*) => {};
+}
+
+// Expands the given items only if the **utoipa** feature is enabled.
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_utoipa {
+  ($($item:item)*) => {
+    $($item)*
+  };
+}
+
+#[cfg(not(feature = "utoipa"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_utoipa {
+  ($($item:item)*) => {};
+}
+
+#[cfg(test)]
+mod tests {
+  use std::convert::TryFrom;
+
+  use crate::{HexTypeError, LowerHexString, UpperHexString};
+
+  crate::define_hex_type!(TxHash, 4, Lower);
+  crate::define_hex_type!(
+    /// Documented key.
+    pub(crate) Key, 2, Upper,
+  );
+
+  #[test]
+  fn it_constructs_from_str() {
+    assert_eq!(TxHash::new("142a020a"), Ok(TxHash([20, 42, 2, 10])));
+    assert_eq!("142A".parse::<Key>(), Ok(Key([20, 42])));
+    assert_eq!(TxHash::BYTE_LEN, 4);
+    assert_eq!(Key::BYTE_LEN, 2);
+  }
+
+  #[test]
+  fn it_rejects_invalid_str() {
+    let error = TxHash::new("142A020A").unwrap_err();
+
+    assert_eq!(error.type_name(), "TxHash");
+    assert_eq!(
+      error.error(),
+      &crate::Error::InvalidCase {
+        expected: crate::Case::Lower,
+        found: crate::Case::Upper,
+        index: 3
+      }
+    );
+    assert_eq!(
+      "142a".parse::<TxHash>(),
+      Err(HexTypeError::new(
+        "TxHash",
+        crate::Error::InvalidByteLength {
+          len: 2,
+          min: 4,
+          max: 4
+        }
+      ))
+    );
+    assert_eq!(
+      "142a".parse::<TxHash>().unwrap_err().to_string(),
+      "Invalid TxHash: Invalid byte length 2, expected between 4 and 4 bytes"
+    );
+  }
+
+  #[test]
+  fn it_converts_from_and_into_bytes_and_hex_str() {
+    let hash = TxHash::from([20, 42, 2, 10]);
+
+    assert_eq!(<[u8; 4]>::from(hash), [20, 42, 2, 10]);
+    assert_eq!(hash.to_string(), "142a020a");
+    assert_eq!(
+      LowerHexString::from(hash),
+      LowerHexString::new("142a020a").unwrap()
+    );
+    assert_eq!(
+      Key::try_from(UpperHexString::new("142A").unwrap()),
+      Ok(Key::from_bytes([20, 42]))
+    );
+  }
+
+  #[cfg(feature = "serde")]
+  mod serde {
+    use super::*;
+
+    #[test]
+    fn it_ser_and_deser_hex_type() {
+      let hash = TxHash::from([20, 42, 2, 10]);
+
+      assert_eq!(serde_json::to_string(&hash).unwrap(), "\"142a020a\"");
+      assert_eq!(
+        serde_json::from_str::<TxHash>("\"142a020a\"").unwrap(),
+        hash
+      );
+    }
+
+    #[test]
+    fn it_fails_to_deser_invalid_hex_type() {
+      let error = serde_json::from_str::<TxHash>("\"142a\"").unwrap_err();
+
+      assert!(error.to_string().starts_with("Invalid TxHash"));
+    }
+  }
+}
@@ -0,0 +1,256 @@
+//! Streaming decoding of large hexadecimal files through memory mapping.
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::{Case, Error, HexString};
+
+/// Reader of the decoded bytes of a memory-mapped hexadecimal file.
+///
+/// The file is decoded on demand, without loading its whole text into memory. Skipped bytes, such
+/// as whitespaces and newlines, may appear anywhere in the file, even between the two characters
+/// of a byte.
+///
+/// Reading fails with an [`io::ErrorKind::InvalidData`] error wrapping an [`Error`] if the file
+/// contains a non-hexadecimal character or a hexadecimal character of the opposite case, whose
+/// index is its position in the file, or an odd number of hexadecimal characters.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::io::Read;
+/// use hexstring::{HexFileReader, LowerHexString};
+///
+/// let bytes = LowerHexString::decode_file("dump.hex")?;
+///
+/// let mut reader = HexFileReader::<{ hexstring::Case::Lower }>::open("dump.hex")?
+///   .with_skip(|&b| b.is_ascii_whitespace() || b == b':');
+/// let mut header = [0u8; 16];
+///
+/// reader.read_exact(&mut header)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct HexFileReader<const C: Case> {
+  mmap: Mmap,
+  position: usize,
+  skip: fn(&u8) -> bool,
+}
+
+impl<const C: Case> HexFileReader<C> {
+  /// Opens and memory-maps the hexadecimal file at the given path, skipping ASCII whitespaces.
+  ///
+  /// # Errors
+  /// This method fails if the file cannot be opened or mapped into memory.
+  pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    let file = File::open(path)?;
+
+    // SAFETY: the mapped file is expected not to be modified while being read, as with any
+    // memory-mapped file.
+    let mmap = unsafe { Mmap::map(&file)? };
+
+    Ok(Self {
+      mmap,
+      position: 0,
+      skip: u8::is_ascii_whitespace,
+    })
+  }
+
+  /// Sets the predicate of the bytes to skip while decoding, which are ASCII whitespaces by
+  /// default.
+  pub fn with_skip(self, skip: fn(&u8) -> bool) -> Self {
+    Self { skip, ..self }
+  }
+
+  // Returns the value of the next non-skipped hexadecimal character, if any.
+  fn next_nibble(&mut self) -> io::Result<Option<u8>> {
+    while let Some(&b) = self.mmap.get(self.position) {
+      let index = self.position;
+
+      self.position += 1;
+
+      if (self.skip)(&b) {
+        continue;
+      }
+
+      if !HexString::<C>::is_hex_byte(b) {
+        // the character may span several bytes if not ASCII.
+        let bytes = &self.mmap[index..self.mmap.len().min(index + 4)];
+        let c = String::from_utf8_lossy(bytes).chars().next().unwrap();
+
+        return Err(invalid_data(HexString::<C>::invalid_char(c, index)));
+      }
+
+      return Ok(Some(match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        _ => b - b'A' + 10,
+      }));
+    }
+
+    Ok(None)
+  }
+
+  // Returns the next decoded byte, if any.
+  fn next_byte(&mut self) -> io::Result<Option<u8>> {
+    let high = match self.next_nibble()? {
+      Some(high) => high,
+      None => return Ok(None),
+    };
+    let low = self
+      .next_nibble()?
+      .ok_or_else(|| invalid_data(Error::OddLength))?;
+
+    Ok(Some(high << 4 | low))
+  }
+}
+
+impl<const C: Case> io::Read for HexFileReader<C> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    for (n, out) in buf.iter_mut().enumerate() {
+      let start = self.position;
+
+      match self.next_byte() {
+        Ok(Some(b)) => *out = b,
+        Ok(None) => return Ok(n),
+        // the error is reported by the next read, so that the bytes already read are not lost.
+        Err(_) if n > 0 => {
+          self.position = start;
+          return Ok(n);
+        }
+        Err(e) => return Err(e),
+      }
+    }
+
+    Ok(buf.len())
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Decodes the hexadecimal file at the given path through a [`HexFileReader`], skipping ASCII
+  /// whitespaces.
+  ///
+  /// # Errors
+  /// This method fails if the file cannot be read or is not a valid hexadecimal file, refers to
+  /// [`HexFileReader`] for more details.
+  pub fn decode_file<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+
+    io::Read::read_to_end(&mut HexFileReader::<C>::open(path)?, &mut bytes)?;
+
+    Ok(bytes)
+  }
+}
+
+fn invalid_data(e: Error) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Read;
+  use std::path::PathBuf;
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  fn file(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("hexstring-{}-{}", std::process::id(), name));
+
+    std::fs::write(&path, content).unwrap();
+    path
+  }
+
+  fn error(e: io::Error) -> Error {
+    *e.into_inner().unwrap().downcast::<Error>().unwrap()
+  }
+
+  #[test]
+  fn it_decodes_file() {
+    let path = file("valid", "dead beef\n00 1\r\n1\n");
+
+    assert_eq!(
+      LowerHexString::decode_file(&path).unwrap(),
+      vec![0xde, 0xad, 0xbe, 0xef, 0x00, 0x11]
+    );
+    assert_eq!(
+      UpperHexString::decode_file(file("empty", "")).unwrap(),
+      Vec::<u8>::new()
+    );
+  }
+
+  #[test]
+  fn it_reads_file_by_chunks() {
+    let path = file("chunks", "DeadBeef0011");
+    let mut reader = HexFileReader::<{ Case::Any }>::open(&path).unwrap();
+    let mut buf = [0u8; 4];
+
+    assert_eq!(reader.read(&mut buf).unwrap(), 4);
+    assert_eq!(buf, [0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(buf[..2], [0x00, 0x11]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+  }
+
+  #[test]
+  fn it_reads_valid_bytes_before_failing() {
+    let mut reader = HexFileReader::<{ Case::Lower }>::open(file("partial", "dead zz")).unwrap();
+    let mut buf = [0u8; 4];
+
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(buf[..2], [0xde, 0xad]);
+    assert_eq!(
+      error(reader.read(&mut buf).unwrap_err()),
+      Error::InvalidHexCharacter { c: 'z', index: 5 }
+    );
+
+    let mut reader =
+      HexFileReader::<{ Case::Lower }>::open(file("partial-odd", "dead\na")).unwrap();
+
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(error(reader.read(&mut buf).unwrap_err()), Error::OddLength);
+  }
+
+  #[test]
+  fn it_skips_custom_bytes() {
+    let path = file("custom", "de:ad:be:ef");
+    let mut reader = HexFileReader::<{ Case::Lower }>::open(&path)
+      .unwrap()
+      .with_skip(|&b| b == b':');
+    let mut bytes = Vec::new();
+
+    reader.read_to_end(&mut bytes).unwrap();
+
+    assert_eq!(bytes, vec![0xde, 0xad, 0xbe, 0xef]);
+    assert!(AnyCaseHexString::decode_file(&path).is_err());
+  }
+
+  #[test]
+  fn it_fails_to_decode_invalid_file() {
+    assert_eq!(
+      error(LowerHexString::decode_file(file("upper", "de\nAD")).unwrap_err()),
+      Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 3
+      }
+    );
+    assert_eq!(
+      error(AnyCaseHexString::decode_file(file("utf8", "00é0")).unwrap_err()),
+      Error::InvalidHexCharacter { c: 'é', index: 2 }
+    );
+    assert_eq!(
+      error(AnyCaseHexString::decode_file(file("odd", "abc \n")).unwrap_err()),
+      Error::OddLength
+    );
+    assert_eq!(
+      LowerHexString::decode_file(file("missing", "").with_extension("missing"))
+        .unwrap_err()
+        .kind(),
+      io::ErrorKind::NotFound
+    );
+  }
+}
@@ -0,0 +1,133 @@
+//! Nibble-level access to hexadecimal strings.
+
+use crate::{Case, Error, HexDigit, HexString};
+
+impl<const C: Case> HexString<C> {
+  /// Returns an iterator over the nibbles of the [`HexString`], from the most significant one of
+  /// the first byte.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("0a1f").unwrap();
+  ///
+  /// assert_eq!(hex.nibbles().collect::<Vec<_>>(), vec![0, 10, 1, 15]);
+  /// ```
+  pub fn nibbles(&self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator + '_ {
+    self.0.bytes().map(crate::decode_nibble)
+  }
+
+  /// Returns the nibble at the given position, or `None` if it is out of bounds.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::UpperHexString;
+  ///
+  /// let hex = UpperHexString::new("0A1F").unwrap();
+  ///
+  /// assert_eq!(hex.get_nibble(1), Some(10));
+  /// assert_eq!(hex.get_nibble(4), None);
+  /// ```
+  pub fn get_nibble(&self, index: usize) -> Option<u8> {
+    self
+      .0
+      .as_bytes()
+      .get(index)
+      .copied()
+      .map(crate::decode_nibble)
+  }
+
+  /// Returns the number of nibbles of the [`HexString`], which is twice its number of bytes.
+  pub fn nibble_len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Constructs a new [`HexString`] from nibbles, the first one being the most significant nibble
+  /// of the first byte.
+  ///
+  /// # Errors
+  /// This method fails if there is an odd number of nibbles.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::{Error, HexDigit, LowerHexString};
+  ///
+  /// let nibbles = [0xd, 0xe, 0xa, 0xd].iter().map(|&n| HexDigit::new(n).unwrap());
+  ///
+  /// assert_eq!(
+  ///   LowerHexString::from_nibbles(nibbles),
+  ///   Ok(LowerHexString::new("dead").unwrap())
+  /// );
+  /// assert_eq!(
+  ///   LowerHexString::from_nibbles(HexDigit::new(1)),
+  ///   Err(Error::OddLength)
+  /// );
+  /// ```
+  pub fn from_nibbles<I: IntoIterator<Item = HexDigit>>(nibbles: I) -> Result<Self, Error> {
+    let s = nibbles
+      .into_iter()
+      .map(|digit| digit.to_char(C))
+      .collect::<String>();
+
+    if s.len() & 1 != 0 {
+      return Err(Error::OddLength);
+    }
+
+    Ok(Self(s.into()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::borrow::Cow;
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_iterates_over_nibbles() {
+    let hex = AnyCaseHexString::new("0aF9").unwrap();
+
+    assert_eq!(hex.nibbles().collect::<Vec<_>>(), vec![0, 10, 15, 9]);
+    assert_eq!(hex.nibbles().next_back(), Some(9));
+    assert_eq!(hex.nibbles().len(), 4);
+    assert_eq!(hex.nibble_len(), 4);
+    assert_eq!(hex.get_nibble(2), Some(15));
+    assert_eq!(hex.get_nibble(4), None);
+    assert_eq!(LowerHexString::default().nibbles().next(), None);
+  }
+
+  #[test]
+  fn it_does_not_panic_on_invalid_str() {
+    let hex: LowerHexString = HexString(Cow::Borrowed("0z"));
+
+    assert_eq!(hex.nibbles().collect::<Vec<_>>(), vec![0, 0]);
+    assert_eq!(hex.get_nibble(1), Some(0));
+  }
+
+  #[test]
+  fn it_constructs_from_nibbles() {
+    let nibbles = || (0..4).map(|n| HexDigit::new(n * 4).unwrap());
+
+    assert_eq!(
+      UpperHexString::from_nibbles(nibbles()),
+      Ok(UpperHexString::new("048C").unwrap())
+    );
+    assert_eq!(
+      LowerHexString::from_nibbles(nibbles()),
+      Ok(LowerHexString::new("048c").unwrap())
+    );
+    assert_eq!(
+      LowerHexString::from_nibbles(nibbles().skip(1)),
+      Err(Error::OddLength)
+    );
+    assert_eq!(
+      LowerHexString::from_nibbles(std::iter::empty()),
+      Ok(LowerHexString::default())
+    );
+  }
+}
@@ -0,0 +1,246 @@
+//! Numeric comparison and hashing.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::{Case, HexString};
+
+impl<const C: Case> HexString<C> {
+  /// Compares the values of two [`HexString`] as big-endian unsigned integers.
+  ///
+  /// Unlike the lexicographic order of the strings, leading zeros are ignored so that values of
+  /// different lengths are compared by magnitude, whatever their case.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::cmp::Ordering;
+  /// use hexstring::{LowerHexString, UpperHexString};
+  ///
+  /// let a = LowerHexString::new("ff").unwrap();
+  /// let b = LowerHexString::new("0100").unwrap();
+  ///
+  /// assert_eq!(a.cmp(&b), Ordering::Greater);
+  /// assert_eq!(a.cmp_numeric(&b), Ordering::Less);
+  /// assert_eq!(a.cmp_numeric(&UpperHexString::new("00FF").unwrap()), Ordering::Equal);
+  /// ```
+  pub fn cmp_numeric<const C2: Case>(&self, other: &HexString<C2>) -> Ordering {
+    let a = significant_digits(self);
+    let b = significant_digits(other);
+
+    a.len()
+      .cmp(&b.len())
+      .then_with(|| lowercase_bytes(a).cmp(lowercase_bytes(b)))
+  }
+
+  /// Returns the number of leading zero bits of the decoded value.
+  ///
+  /// All the bits are counted if the value is zero, such as for a hash below a proof-of-work
+  /// difficulty target.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// assert_eq!(LowerHexString::new("000f2a").unwrap().leading_zero_bits(), 12);
+  /// assert_eq!(LowerHexString::new("0000").unwrap().leading_zero_bits(), 16);
+  /// ```
+  pub fn leading_zero_bits(&self) -> usize {
+    let s = self.0.as_bytes();
+
+    match s.iter().position(|&b| b != b'0') {
+      Some(i) => 4 * i + (crate::decode_nibble(s[i]).leading_zeros() - 4) as usize,
+      None => 4 * s.len(),
+    }
+  }
+
+  /// Returns the number of trailing zero bits of the decoded value.
+  ///
+  /// All the bits are counted if the value is zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// assert_eq!(LowerHexString::new("2a80").unwrap().trailing_zero_bits(), 7);
+  /// assert_eq!(LowerHexString::new("0000").unwrap().trailing_zero_bits(), 16);
+  /// ```
+  pub fn trailing_zero_bits(&self) -> usize {
+    let s = self.0.as_bytes();
+
+    match s.iter().rposition(|&b| b != b'0') {
+      Some(i) => 4 * (s.len() - 1 - i) + crate::decode_nibble(s[i]).trailing_zeros() as usize,
+      None => 4 * s.len(),
+    }
+  }
+}
+
+/// Wraps a [`HexString`] so that its equality, ordering and hashing are those of its value as a
+/// big-endian unsigned integer.
+///
+/// It allows values of different lengths, such as counters or balances, to be sorted by magnitude
+/// and to be deduplicated regardless of their leading zeros.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{LowerHexString, NumericOrd};
+///
+/// let mut values = vec![
+///   NumericOrd(LowerHexString::new("0100").unwrap()),
+///   NumericOrd(LowerHexString::new("ff").unwrap()),
+///   NumericOrd(LowerHexString::new("000002").unwrap()),
+/// ];
+///
+/// values.sort();
+///
+/// assert_eq!(
+///   values.iter().map(ToString::to_string).collect::<Vec<_>>(),
+///   vec!["000002", "ff", "0100"]
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct NumericOrd<T>(pub T);
+
+impl<T> NumericOrd<T> {
+  /// Consumes the [`NumericOrd`], returning the wrapped value.
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<const C: Case> From<HexString<C>> for NumericOrd<HexString<C>> {
+  fn from(s: HexString<C>) -> Self {
+    Self(s)
+  }
+}
+
+impl<const C: Case> fmt::Display for NumericOrd<HexString<C>> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&self.0, f)
+  }
+}
+
+impl<const C: Case, const C2: Case> PartialEq<NumericOrd<HexString<C2>>>
+  for NumericOrd<HexString<C>>
+{
+  fn eq(&self, other: &NumericOrd<HexString<C2>>) -> bool {
+    self.0.cmp_numeric(&other.0) == Ordering::Equal
+  }
+}
+
+impl<const C: Case> Eq for NumericOrd<HexString<C>> {}
+
+impl<const C: Case, const C2: Case> PartialOrd<NumericOrd<HexString<C2>>>
+  for NumericOrd<HexString<C>>
+{
+  fn partial_cmp(&self, other: &NumericOrd<HexString<C2>>) -> Option<Ordering> {
+    Some(self.0.cmp_numeric(&other.0))
+  }
+}
+
+impl<const C: Case> Ord for NumericOrd<HexString<C>> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.0.cmp_numeric(&other.0)
+  }
+}
+
+impl<const C: Case> Hash for NumericOrd<HexString<C>> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    for b in lowercase_bytes(significant_digits(&self.0)) {
+      state.write_u8(b);
+    }
+
+    // mimics `str` hashing to prevent prefix collisions.
+    state.write_u8(0xff);
+  }
+}
+
+// Returns the digits of the value without its leading zeros.
+fn significant_digits<const C: Case>(s: &HexString<C>) -> &[u8] {
+  let s = s.0.as_bytes();
+  let start = s.iter().position(|&b| b != b'0').unwrap_or(s.len());
+
+  &s[start..]
+}
+
+fn lowercase_bytes(s: &[u8]) -> impl Iterator<Item = u8> + '_ {
+  s.iter().map(|b| b.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::hash_map::DefaultHasher;
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    value.hash(&mut hasher);
+    hasher.finish()
+  }
+
+  #[test]
+  fn it_compares_numerically() {
+    let hex = LowerHexString::new("00ff").unwrap();
+
+    assert_eq!(
+      hex.cmp_numeric(&UpperHexString::new("FF").unwrap()),
+      Ordering::Equal
+    );
+    assert_eq!(
+      hex.cmp_numeric(&LowerHexString::new("0100").unwrap()),
+      Ordering::Less
+    );
+    assert_eq!(
+      hex.cmp_numeric(&AnyCaseHexString::new("00000000Fe").unwrap()),
+      Ordering::Greater
+    );
+    assert_eq!(
+      LowerHexString::new("0000")
+        .unwrap()
+        .cmp_numeric(&LowerHexString::default()),
+      Ordering::Equal
+    );
+    assert_eq!(
+      LowerHexString::default().cmp_numeric(&LowerHexString::new("01").unwrap()),
+      Ordering::Less
+    );
+  }
+
+  #[test]
+  fn it_counts_zero_bits() {
+    let hex = UpperHexString::new("001F8000").unwrap();
+
+    assert_eq!(hex.leading_zero_bits(), 11);
+    assert_eq!(hex.trailing_zero_bits(), 15);
+    assert_eq!(LowerHexString::new("80").unwrap().leading_zero_bits(), 0);
+    assert_eq!(LowerHexString::new("01").unwrap().trailing_zero_bits(), 0);
+    assert_eq!(
+      AnyCaseHexString::new("0000").unwrap().leading_zero_bits(),
+      16
+    );
+    assert_eq!(LowerHexString::default().leading_zero_bits(), 0);
+    assert_eq!(LowerHexString::default().trailing_zero_bits(), 0);
+  }
+
+  #[test]
+  fn it_orders_and_hashes_numerically() {
+    let a = NumericOrd(UpperHexString::new("000A").unwrap());
+    let b = NumericOrd(UpperHexString::new("0A").unwrap());
+    let c = NumericOrd(UpperHexString::new("0100").unwrap());
+
+    assert!(a == b);
+    assert!(b < c);
+    assert!(a == NumericOrd(LowerHexString::new("0a").unwrap()));
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(hash(&a), hash(&c));
+    assert_eq!(a.cmp(&b), Ordering::Equal);
+  }
+}
@@ -0,0 +1,165 @@
+//! Incremental decoding of hexadecimal input arriving by chunks.
+
+use crate::{Case, Error, HexString};
+
+/// Bytes decoded from a chunk of input pushed into a [`HexParser`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecodedChunk(Vec<u8>);
+
+impl DecodedChunk {
+  /// Returns the decoded bytes.
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.0
+  }
+
+  /// Consumes the [`DecodedChunk`], returning the decoded bytes.
+  pub fn into_bytes(self) -> Vec<u8> {
+    self.0
+  }
+}
+
+impl AsRef<[u8]> for DecodedChunk {
+  fn as_ref(&self) -> &[u8] {
+    &self.0
+  }
+}
+
+impl From<DecodedChunk> for Vec<u8> {
+  fn from(chunk: DecodedChunk) -> Self {
+    chunk.0
+  }
+}
+
+/// Push-based parser validating and decoding a hexadecimal string of the expected case, whose
+/// characters arrive by chunks such as network reads.
+///
+/// A byte whose two characters are split across chunks is decoded with the chunk holding its
+/// second character.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{Case, HexParser};
+///
+/// let mut parser = HexParser::<{ Case::Lower }>::new();
+///
+/// assert_eq!(parser.push(b"dea").unwrap().as_bytes(), &[0xde]);
+/// assert_eq!(parser.push(b"dbeef").unwrap().as_bytes(), &[0xad, 0xbe, 0xef]);
+///
+/// parser.finish().unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct HexParser<const C: Case> {
+  // value of the first character of a byte whose second character has not been pushed yet.
+  pending: Option<u8>,
+  position: usize,
+}
+
+impl<const C: Case> HexParser<C> {
+  /// Constructs a new [`HexParser`].
+  pub fn new() -> Self {
+    Self {
+      pending: None,
+      position: 0,
+    }
+  }
+
+  /// Returns the number of characters pushed so far.
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  /// Validates and decodes the given chunk of characters.
+  ///
+  /// # Errors
+  /// This method fails if the chunk contains a non-hexadecimal character of the expected case,
+  /// whose index is its position in the whole input. The [`HexParser`] is then left unchanged.
+  pub fn push(&mut self, chunk: &[u8]) -> Result<DecodedChunk, Error> {
+    let mut bytes = Vec::with_capacity(chunk.len().div_ceil(2));
+    let mut pending = self.pending;
+
+    for (i, &b) in chunk.iter().enumerate() {
+      if !HexString::<C>::is_hex_byte(b) {
+        let c = String::from_utf8_lossy(&chunk[i..chunk.len().min(i + 4)])
+          .chars()
+          .next()
+          .unwrap();
+
+        return Err(HexString::<C>::invalid_char(c, self.position + i));
+      }
+
+      let nibble = match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        _ => b - b'A' + 10,
+      };
+
+      match pending.take() {
+        Some(high) => bytes.push(high << 4 | nibble),
+        None => pending = Some(nibble),
+      }
+    }
+
+    self.pending = pending;
+    self.position += chunk.len();
+
+    Ok(DecodedChunk(bytes))
+  }
+
+  /// Ends the parsing, checking that no character is left alone.
+  ///
+  /// # Errors
+  /// This method fails if an odd number of characters has been pushed.
+  pub fn finish(self) -> Result<(), Error> {
+    match self.pending {
+      Some(_) => Err(Error::OddLength),
+      None => Ok(()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_decodes_chunks() {
+    let mut parser = HexParser::<{ Case::Upper }>::new();
+    let mut bytes = Vec::new();
+
+    for chunk in [&b"D"[..], b"", b"EADB", b"E", b"EF0", b"011"] {
+      bytes.extend(parser.push(chunk).unwrap().into_bytes());
+    }
+
+    assert_eq!(bytes, vec![0xde, 0xad, 0xbe, 0xef, 0x00, 0x11]);
+    assert_eq!(parser.position(), 12);
+    assert_eq!(parser.finish(), Ok(()));
+  }
+
+  #[test]
+  fn it_rejects_invalid_chunk() {
+    let mut parser = HexParser::<{ Case::Lower }>::new();
+
+    assert_eq!(parser.push(b"abc").unwrap().as_bytes(), &[0xab]);
+    assert_eq!(
+      parser.push(b"d0Z"),
+      Err(Error::InvalidHexCharacter { c: 'Z', index: 5 })
+    );
+    assert_eq!(
+      parser.push("dé".as_bytes()),
+      Err(Error::InvalidHexCharacter { c: 'é', index: 4 })
+    );
+    assert_eq!(parser.position(), 3);
+    assert_eq!(parser.push(b"d").map(Vec::from), Ok(vec![0xcd]));
+  }
+
+  #[test]
+  fn it_rejects_odd_number_of_chars() {
+    let mut parser = HexParser::<{ Case::Any }>::new();
+
+    parser.push(b"aBc").unwrap();
+
+    assert_eq!(parser.finish(), Err(Error::OddLength));
+    assert_eq!(HexParser::<{ Case::Any }>::default().finish(), Ok(()));
+  }
+}
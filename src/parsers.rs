@@ -0,0 +1,233 @@
+//! Parser combinators recognizing hexadecimal string within [nom](https://docs.rs/nom) and
+//! [winnow](https://docs.rs/winnow) grammars.
+//!
+//! The parsers consume the longest run of hexadecimal characters of the expected case, truncated
+//! to an even number of characters so that it always represents whole bytes. They return a
+//! [`GenericHexString`] borrowing the input, which can be converted into an owned
+//! [`HexString`](crate::HexString) with `HexString::from(&hex)`.
+
+use std::ops::RangeInclusive;
+
+use crate::{Case, GenericHexString, HexString};
+
+// Returns the number of leading characters of the input forming a hexadecimal string whose number
+// of bytes lies within the given range, or `None` if there are too few of them.
+fn hex_run_len<const C: Case>(input: &str, bytes: &RangeInclusive<usize>) -> Option<usize> {
+  let max_len = bytes.end().saturating_mul(2);
+  let len = input
+    .bytes()
+    .take(max_len)
+    .take_while(|&b| HexString::<C>::is_hex_byte(b))
+    .count()
+    & !1;
+
+  if len / 2 >= *bytes.start() {
+    Some(len)
+  } else {
+    None
+  }
+}
+
+// Splits the input after the given number of hexadecimal characters.
+fn split_hex<const C: Case>(input: &str, len: usize) -> (GenericHexString<C, &str>, &str) {
+  let (hex, rest) = input.split_at(len);
+
+  // SAFETY: the first `len` characters have been checked by `hex_run_len`.
+  (unsafe { GenericHexString::new_unchecked(hex) }, rest)
+}
+
+/// Parsers for the [nom](https://docs.rs/nom) parser combinator library.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::parsers::nom::hex_string;
+/// use hexstring::LowerHexString;
+/// use nom::bytes::complete::tag;
+/// use nom::sequence::preceded;
+/// use nom::IResult;
+///
+/// fn tx_id(input: &str) -> IResult<&str, LowerHexString> {
+///   preceded(tag("tx="), hex_string)(input).map(|(rest, hex)| (rest, LowerHexString::from(&hex)))
+/// }
+///
+/// let (rest, id) = tx_id("tx=deadbeef;").unwrap();
+///
+/// assert_eq!(id.to_string(), "deadbeef");
+/// assert_eq!(rest, ";");
+/// ```
+#[cfg(feature = "nom")]
+pub mod nom {
+  use std::ops::RangeInclusive;
+
+  use ::nom::error::{ErrorKind, ParseError};
+  use ::nom::{Err, IResult};
+
+  use crate::{Case, GenericHexString};
+
+  /// Recognizes a non-empty hexadecimal string of the expected case.
+  ///
+  /// # Errors
+  /// This parser fails with [`ErrorKind::HexDigit`] if the input does not start with at least one
+  /// byte in hexadecimal.
+  pub fn hex_string<'a, const C: Case, E: ParseError<&'a str>>(
+    input: &'a str,
+  ) -> IResult<&'a str, GenericHexString<C, &'a str>, E> {
+    hex_string_bounded(1..=usize::MAX)(input)
+  }
+
+  /// Returns a parser recognizing a hexadecimal string of the expected case whose number of bytes
+  /// lies within the given range.
+  ///
+  /// The parser stops after the maximum number of bytes, leaving the remaining characters in the
+  /// input.
+  ///
+  /// # Errors
+  /// The parser fails with [`ErrorKind::HexDigit`] if the input does not start with at least the
+  /// minimum number of bytes in hexadecimal.
+  pub fn hex_string_bounded<'a, const C: Case, E: ParseError<&'a str>>(
+    bytes: RangeInclusive<usize>,
+  ) -> impl FnMut(&'a str) -> IResult<&'a str, GenericHexString<C, &'a str>, E> {
+    move |input| match super::hex_run_len::<C>(input, &bytes) {
+      Some(len) => {
+        let (hex, rest) = super::split_hex(input, len);
+
+        Ok((rest, hex))
+      }
+      None => Err(Err::Error(E::from_error_kind(input, ErrorKind::HexDigit))),
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use ::nom::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_hex_string() {
+      let (rest, hex) = hex_string::<{ Case::Lower }, Error<_>>("0a1bC").unwrap();
+
+      assert_eq!(hex.as_str(), "0a1b");
+      assert_eq!(rest, "C");
+
+      let (rest, hex) = hex_string::<{ Case::Any }, Error<_>>("0a1bC").unwrap();
+
+      assert_eq!(hex.as_str(), "0a1b");
+      assert_eq!(rest, "C");
+      assert_eq!(
+        hex_string::<{ Case::Upper }, Error<_>>("0a"),
+        Err(Err::Error(Error::new("0a", ErrorKind::HexDigit)))
+      );
+      assert!(hex_string::<{ Case::Lower }, Error<_>>("").is_err());
+    }
+
+    #[test]
+    fn it_parses_bounded_hex_string() {
+      let mut parser = hex_string_bounded::<{ Case::Upper }, Error<_>>(2..=3);
+
+      assert_eq!(parser("0A0B0C0D").map(|(rest, _)| rest), Ok("0D"));
+      assert_eq!(
+        parser("0A0B").map(|(rest, hex)| (rest, hex.into_storage())),
+        Ok(("", "0A0B"))
+      );
+      assert!(parser("0A").is_err());
+      assert!(hex_string_bounded::<{ Case::Lower }, Error<_>>(0..=1)("xyz").is_ok());
+    }
+  }
+}
+
+/// Parsers for the [winnow](https://docs.rs/winnow) parser combinator library.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::parsers::winnow::hex_string;
+/// use hexstring::UpperHexString;
+/// use winnow::prelude::*;
+///
+/// fn key(input: &mut &str) -> ModalResult<UpperHexString> {
+///   ("key=", hex_string).map(|(_, hex)| UpperHexString::from(&hex)).parse_next(input)
+/// }
+///
+/// let mut input = "key=CAFE BABE";
+///
+/// assert_eq!(key(&mut input).unwrap().to_string(), "CAFE");
+/// assert_eq!(input, " BABE");
+/// ```
+#[cfg(feature = "winnow")]
+pub mod winnow {
+  use std::ops::RangeInclusive;
+
+  use ::winnow::error::ParserError;
+  use ::winnow::Parser;
+
+  use crate::{Case, GenericHexString};
+
+  /// Recognizes a non-empty hexadecimal string of the expected case.
+  ///
+  /// # Errors
+  /// This parser fails if the input does not start with at least one byte in hexadecimal.
+  pub fn hex_string<'a, const C: Case, E: ParserError<&'a str>>(
+    input: &mut &'a str,
+  ) -> Result<GenericHexString<C, &'a str>, E> {
+    hex_string_bounded(1..=usize::MAX).parse_next(input)
+  }
+
+  /// Returns a parser recognizing a hexadecimal string of the expected case whose number of bytes
+  /// lies within the given range.
+  ///
+  /// The parser stops after the maximum number of bytes, leaving the remaining characters in the
+  /// input.
+  ///
+  /// # Errors
+  /// The parser fails if the input does not start with at least the minimum number of bytes in
+  /// hexadecimal.
+  pub fn hex_string_bounded<'a, const C: Case, E: ParserError<&'a str>>(
+    bytes: RangeInclusive<usize>,
+  ) -> impl Parser<&'a str, GenericHexString<C, &'a str>, E> {
+    move |input: &mut &'a str| match super::hex_run_len::<C>(input, &bytes) {
+      Some(len) => {
+        let (hex, rest) = super::split_hex(input, len);
+
+        *input = rest;
+        Ok(hex)
+      }
+      None => Err(E::from_input(input)),
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use ::winnow::error::ContextError;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_hex_string() {
+      let mut input = "0A1Bc";
+      let hex = hex_string::<{ Case::Upper }, ContextError>(&mut input).unwrap();
+
+      assert_eq!(hex.as_str(), "0A1B");
+      assert_eq!(input, "c");
+
+      let mut input = "0A1";
+      let hex = hex_string::<{ Case::Any }, ContextError>(&mut input).unwrap();
+
+      assert_eq!(hex.as_str(), "0A");
+      assert_eq!(input, "1");
+      assert!(hex_string::<{ Case::Lower }, ContextError>(&mut "0A").is_err());
+    }
+
+    #[test]
+    fn it_parses_bounded_hex_string() {
+      let mut parser = hex_string_bounded::<{ Case::Lower }, ContextError>(1..=2);
+      let mut input = "0a0b0c";
+
+      assert_eq!(parser.parse_next(&mut input).unwrap().as_str(), "0a0b");
+      assert_eq!(input, "0c");
+      assert!(parser.parse("0a0b0c").is_err());
+      assert!(parser.parse_next(&mut "").is_err());
+    }
+  }
+}
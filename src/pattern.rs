@@ -0,0 +1,166 @@
+//! Matching of decoded bytes at the edges of hexadecimal strings.
+
+use std::borrow::Cow;
+
+use crate::{Case, HexLike, HexString};
+
+/// Sequence of bytes searched for within a [`HexString`], given either as raw bytes or as another
+/// hexadecimal string of any case.
+///
+/// This trait is sealed and implemented by:
+/// - byte slices and references to byte arrays
+/// - any [`HexLike`] type, such as [`HexString`]
+pub trait BytePattern: sealed::Sealed {
+  // Returns the pattern as a hexadecimal string of any case.
+  #[doc(hidden)]
+  fn to_hex(&self) -> Cow<'_, str>;
+}
+
+mod sealed {
+  pub trait Sealed {}
+}
+
+impl sealed::Sealed for &[u8] {}
+
+impl BytePattern for &[u8] {
+  fn to_hex(&self) -> Cow<'_, str> {
+    Cow::Owned(crate::display(self).to_string())
+  }
+}
+
+impl<const N: usize> sealed::Sealed for &[u8; N] {}
+
+impl<const N: usize> BytePattern for &[u8; N] {
+  fn to_hex(&self) -> Cow<'_, str> {
+    Cow::Owned(crate::display(&self[..]).to_string())
+  }
+}
+
+impl<T: HexLike> sealed::Sealed for T {}
+
+impl<T: HexLike> BytePattern for T {
+  fn to_hex(&self) -> Cow<'_, str> {
+    Cow::Borrowed(self.as_str())
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Returns the [`HexString`] with the given prefix of bytes removed, or `None` if it does not
+  /// start with it.
+  ///
+  /// The prefix is compared with the decoded bytes, so that a hexadecimal prefix matches whatever
+  /// its case.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::{LowerHexString, UpperHexString};
+  ///
+  /// let hex = LowerHexString::new("cafebabe0034").unwrap();
+  ///
+  /// assert_eq!(
+  ///   hex.strip_prefix_bytes(&[0xca, 0xfe, 0xba, 0xbe]),
+  ///   Some(LowerHexString::new("0034").unwrap())
+  /// );
+  /// assert_eq!(
+  ///   hex.strip_prefix_bytes(UpperHexString::new("CAFE").unwrap()),
+  ///   Some(LowerHexString::new("babe0034").unwrap())
+  /// );
+  /// assert_eq!(hex.strip_prefix_bytes(&[0x00]), None);
+  /// ```
+  pub fn strip_prefix_bytes<P: BytePattern>(&self, prefix: P) -> Option<Self> {
+    let prefix = prefix.to_hex();
+    let s = self.0.as_bytes();
+
+    if s.len() < prefix.len() || !s[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes()) {
+      return None;
+    }
+
+    Some(self.substring(prefix.len()..s.len()))
+  }
+
+  /// Returns the [`HexString`] with the given suffix of bytes removed, or `None` if it does not
+  /// end with it.
+  ///
+  /// The suffix is compared with the decoded bytes, so that a hexadecimal suffix matches whatever
+  /// its case.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("0034cafe").unwrap();
+  ///
+  /// assert_eq!(
+  ///   hex.strip_suffix_bytes(b"\xca\xfe"),
+  ///   Some(LowerHexString::new("0034").unwrap())
+  /// );
+  /// assert_eq!(hex.strip_suffix_bytes(b"\xfe\xca"), None);
+  /// ```
+  pub fn strip_suffix_bytes<P: BytePattern>(&self, suffix: P) -> Option<Self> {
+    let suffix = suffix.to_hex();
+    let s = self.0.as_bytes();
+
+    if s.len() < suffix.len()
+      || !s[s.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+    {
+      return None;
+    }
+
+    Some(self.substring(0..s.len() - suffix.len()))
+  }
+
+  // Returns the given range of characters, borrowing static strings instead of copying them.
+  fn substring(&self, range: std::ops::Range<usize>) -> Self {
+    match &self.0 {
+      Cow::Borrowed(s) => HexString(Cow::Borrowed(&s[range])),
+      Cow::Owned(s) => HexString(Cow::Owned(s[range].to_owned())),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{AnyHexString, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_strips_prefix_bytes() {
+    let hex = UpperHexString::new("7F454C4602").unwrap();
+
+    assert_eq!(
+      hex.strip_prefix_bytes(b"\x7fELF"),
+      Some(UpperHexString::new("02").unwrap())
+    );
+    assert_eq!(
+      hex.strip_prefix_bytes(LowerHexString::new("7f45").unwrap()),
+      Some(UpperHexString::new("4C4602").unwrap())
+    );
+    assert_eq!(
+      hex.strip_prefix_bytes(AnyHexString::new("7f454c4602").unwrap()),
+      Some(UpperHexString::new("").unwrap())
+    );
+    assert_eq!(hex.strip_prefix_bytes(&[][..]), Some(hex.clone()));
+    assert_eq!(
+      hex.strip_prefix_bytes(&[0x7f, 0x45, 0x4c, 0x46, 0x02, 0x00]),
+      None
+    );
+    assert_eq!(hex.strip_prefix_bytes(&[0x45]), None);
+  }
+
+  #[test]
+  fn it_strips_suffix_bytes() {
+    let hex = LowerHexString::new(String::from("00ff0d0a")).unwrap();
+
+    assert_eq!(
+      hex.strip_suffix_bytes(b"\r\n"),
+      Some(LowerHexString::new("00ff").unwrap())
+    );
+    assert_eq!(
+      hex.strip_suffix_bytes(UpperHexString::new("FF0D0A").unwrap()),
+      Some(LowerHexString::new("00").unwrap())
+    );
+    assert_eq!(hex.strip_suffix_bytes(&[0x0d]), None);
+    assert_eq!(hex.strip_suffix_bytes(&[0xf0, 0xd0]), None);
+  }
+}
@@ -0,0 +1,78 @@
+//! PostgreSQL integration through the `postgres-types` traits used by `tokio-postgres`.
+//!
+//! A [`HexString`] is stored as text in `TEXT`, `VARCHAR`, `CHAR` and `NAME` columns, and as its
+//! decoded bytes in `BYTEA` columns.
+
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{Case, HexString};
+
+impl<const C: Case> ToSql for HexString<C> {
+  fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    if *ty == Type::BYTEA {
+      <&[u8] as ToSql>::to_sql(&&self.decode()[..], ty, out)
+    } else {
+      <&str as ToSql>::to_sql(&&*self.0, ty, out)
+    }
+  }
+
+  accepts!(TEXT, VARCHAR, BPCHAR, NAME, UNKNOWN, BYTEA);
+
+  to_sql_checked!();
+}
+
+impl<'a, const C: Case> FromSql<'a> for HexString<C> {
+  fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+    if *ty == Type::BYTEA {
+      Ok(Self::from(<&[u8] as FromSql>::from_sql(ty, raw)?))
+    } else {
+      Ok(Self::new(<&str as FromSql>::from_sql(ty, raw)?.to_owned())?)
+    }
+  }
+
+  accepts!(TEXT, VARCHAR, BPCHAR, NAME, UNKNOWN, BYTEA);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_writes_hex_str_as_text_or_bytea() {
+    let hex = LowerHexString::new("deadbeef").unwrap();
+    let mut out = BytesMut::new();
+
+    assert!(matches!(
+      hex.to_sql_checked(&Type::TEXT, &mut out),
+      Ok(IsNull::No)
+    ));
+    assert_eq!(&out[..], b"deadbeef");
+
+    out.clear();
+
+    assert!(matches!(
+      hex.to_sql_checked(&Type::BYTEA, &mut out),
+      Ok(IsNull::No)
+    ));
+    assert_eq!(&out[..], &[0xde, 0xad, 0xbe, 0xef]);
+    assert!(hex.to_sql_checked(&Type::INT8, &mut out).is_err());
+  }
+
+  #[test]
+  fn it_reads_hex_str_from_text_or_bytea() {
+    assert_eq!(
+      UpperHexString::from_sql(&Type::VARCHAR, b"DEAD").unwrap(),
+      UpperHexString::new("DEAD").unwrap()
+    );
+    assert_eq!(
+      UpperHexString::from_sql(&Type::BYTEA, &[0xde, 0xad]).unwrap(),
+      UpperHexString::new("DEAD").unwrap()
+    );
+    assert!(UpperHexString::from_sql(&Type::TEXT, b"dead").is_err());
+    assert!(!<UpperHexString as FromSql>::accepts(&Type::INT8));
+  }
+}
@@ -0,0 +1,95 @@
+//! Property testing strategies through the `proptest` crate.
+//!
+//! Generated [`HexString`] shrink toward shorter values made of zero bytes, and toward lowercase
+//! characters for [`AnyCaseHexString`](crate::AnyCaseHexString).
+
+use ::proptest::arbitrary::{any, Arbitrary};
+use ::proptest::collection::{vec, SizeRange};
+use ::proptest::strategy::{BoxedStrategy, Strategy};
+
+use crate::{Case, HexString};
+
+/// Creates a strategy generating [`HexString`] of up to 100 bytes.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use hexstring::proptest::any_hexstring;
+/// use hexstring::{Case, LowerHexString};
+/// use proptest::proptest;
+///
+/// proptest!(|(hex in any_hexstring::<{ Case::Lower }>())| {
+///   assert_eq!(LowerHexString::from(hex.decode()), hex);
+/// });
+/// ```
+pub fn any_hexstring<const C: Case>() -> BoxedStrategy<HexString<C>> {
+  any::<HexString<C>>()
+}
+
+/// Creates a strategy generating [`HexString`] of exactly `byte_len` bytes.
+pub fn hexstring_of_len<const C: Case>(byte_len: usize) -> impl Strategy<Value = HexString<C>> {
+  hexstring_len_range(byte_len)
+}
+
+/// Creates a strategy generating [`HexString`] whose number of bytes lies within the given range.
+pub fn hexstring_len_range<const C: Case>(
+  byte_len: impl Into<SizeRange>,
+) -> impl Strategy<Value = HexString<C>> {
+  // each byte comes with a flag telling whether it is displayed in uppercase, which only matters
+  // for `Case::Any`.
+  vec((any::<u8>(), any::<bool>()), byte_len).prop_map(|bytes| {
+    let mut s = String::with_capacity(bytes.len() * 2);
+
+    for (byte, uppercase) in bytes {
+      let digits = [byte >> 4, byte & 0x0f].map(|nibble| match (C, uppercase) {
+        (Case::Upper, _) | (Case::Any, true) => b"0123456789ABCDEF"[nibble as usize],
+        _ => b"0123456789abcdef"[nibble as usize],
+      });
+
+      s.extend(digits.map(char::from));
+    }
+
+    unsafe { HexString::new_unchecked(s) }
+  })
+}
+
+impl<const C: Case> Arbitrary for HexString<C> {
+  type Parameters = SizeRange;
+  type Strategy = BoxedStrategy<Self>;
+
+  fn arbitrary_with(byte_len: Self::Parameters) -> Self::Strategy {
+    hexstring_len_range(byte_len).boxed()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::proptest::proptest;
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  proptest! {
+    #[test]
+    fn it_generates_valid_hex_str(
+      lowercase_hex in any_hexstring::<{ Case::Lower }>(),
+      uppercase_hex in any_hexstring::<{ Case::Upper }>(),
+      any_case_hex in any_hexstring::<{ Case::Any }>(),
+    ) {
+      assert!(LowerHexString::new(lowercase_hex.to_string()).is_ok());
+      assert!(UpperHexString::new(uppercase_hex.to_string()).is_ok());
+      assert!(AnyCaseHexString::new(any_case_hex.to_string()).is_ok());
+    }
+
+    #[test]
+    fn it_generates_hex_str_of_given_len(
+      hex in hexstring_of_len::<{ Case::Upper }>(8),
+      other_hex in hexstring_len_range::<{ Case::Any }>(2..4),
+    ) {
+      assert_eq!(hex.0.len(), 16);
+      assert!((4..8).contains(&other_hex.0.len()));
+    }
+  }
+}
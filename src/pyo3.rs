@@ -0,0 +1,126 @@
+//! Python conversions through the `pyo3` traits.
+//!
+//! A [`HexString`] is extracted from a Python `str` holding a hexadecimal string, or from a Python
+//! `bytes` holding the bytes to encode, and is converted into a Python `str`.
+
+use std::convert::Infallible;
+
+use ::pyo3::exceptions::{PyTypeError, PyValueError};
+use ::pyo3::prelude::*;
+use ::pyo3::types::{PyBytes, PyString};
+use ::pyo3::Borrowed;
+
+use crate::{Case, HexString};
+
+impl<const C: Case> FromPyObject<'_, '_> for HexString<C> {
+  type Error = PyErr;
+
+  fn extract(obj: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+    if let Ok(bytes) = obj.cast::<PyBytes>() {
+      return Ok(Self::from(bytes.as_bytes()));
+    }
+
+    match obj.cast::<PyString>() {
+      Ok(s) => {
+        Self::new(s.to_cow()?.into_owned()).map_err(|e| PyValueError::new_err(e.to_string()))
+      }
+      Err(_) => Err(PyTypeError::new_err(format!(
+        "expected str or bytes, got '{}'",
+        obj.get_type().name()?
+      ))),
+    }
+  }
+}
+
+impl<'py, const C: Case> IntoPyObject<'py> for HexString<C> {
+  type Target = PyString;
+  type Output = Bound<'py, Self::Target>;
+  type Error = Infallible;
+
+  fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+    (&self).into_pyobject(py)
+  }
+}
+
+impl<'py, const C: Case> IntoPyObject<'py> for &HexString<C> {
+  type Target = PyString;
+  type Output = Bound<'py, Self::Target>;
+  type Error = Infallible;
+
+  fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+    Ok(PyString::new(py, &self.0))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_extracts_from_str_and_bytes() {
+    Python::initialize();
+
+    Python::attach(|py| {
+      assert_eq!(
+        PyString::new(py, "deadbeef")
+          .extract::<LowerHexString>()
+          .unwrap(),
+        LowerHexString::new("deadbeef").unwrap()
+      );
+      assert_eq!(
+        PyBytes::new(py, &[0xde, 0xad])
+          .extract::<UpperHexString>()
+          .unwrap(),
+        UpperHexString::new("DEAD").unwrap()
+      );
+    });
+  }
+
+  #[test]
+  fn it_fails_to_extract_invalid_object() {
+    Python::initialize();
+
+    Python::attach(|py| {
+      let error = PyString::new(py, "DEADBEEF")
+        .extract::<LowerHexString>()
+        .unwrap_err();
+
+      assert!(error.is_instance_of::<PyValueError>(py));
+      assert_eq!(
+        error.value(py).to_string(),
+        "Invalid case at position 0 (expected Lower, found Upper)"
+      );
+
+      let error = 42i32
+        .into_pyobject(py)
+        .unwrap()
+        .extract::<LowerHexString>()
+        .unwrap_err();
+
+      assert!(error.is_instance_of::<PyTypeError>(py));
+      assert_eq!(
+        error.value(py).to_string(),
+        "expected str or bytes, got 'int'"
+      );
+    });
+  }
+
+  #[test]
+  fn it_converts_into_str() {
+    Python::initialize();
+
+    Python::attach(|py| {
+      let hex = UpperHexString::new("DEADBEEF").unwrap();
+
+      assert_eq!(
+        (&hex).into_pyobject(py).unwrap().to_str().unwrap(),
+        "DEADBEEF"
+      );
+      assert_eq!(
+        hex.into_pyobject(py).unwrap().extract::<String>().unwrap(),
+        "DEADBEEF"
+      );
+    });
+  }
+}
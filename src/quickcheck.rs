@@ -0,0 +1,71 @@
+//! Property testing support through the `quickcheck` crate.
+
+use ::quickcheck::{Arbitrary, Gen};
+
+use crate::{Case, HexString};
+
+impl<const C: Case> Arbitrary for HexString<C> {
+  /// Generates a [`HexString`] of random bytes, whose characters are of random case for
+  /// [`AnyCaseHexString`](crate::AnyCaseHexString).
+  fn arbitrary(g: &mut Gen) -> Self {
+    let hex = Self::from(Vec::<u8>::arbitrary(g));
+
+    match C {
+      Case::Any => {
+        let s = hex
+          .0
+          .chars()
+          .map(|c| {
+            if bool::arbitrary(g) {
+              c.to_ascii_uppercase()
+            } else {
+              c
+            }
+          })
+          .collect::<String>();
+
+        unsafe { Self::new_unchecked(s) }
+      }
+      _ => hex,
+    }
+  }
+
+  /// Shrinks the [`HexString`] by removing bytes and zeroing them, yielding lowercase values for
+  /// [`AnyCaseHexString`](crate::AnyCaseHexString).
+  fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+    let bytes = hex::decode(self.0.as_ref()).unwrap_or_default();
+
+    Box::new(bytes.shrink().map(Self::from))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::quickcheck::quickcheck;
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  quickcheck! {
+    fn it_generates_valid_hex_str(
+      lowercase_hex: LowerHexString,
+      uppercase_hex: UpperHexString,
+      any_case_hex: AnyCaseHexString
+    ) -> bool {
+      LowerHexString::new(lowercase_hex.to_string()).is_ok()
+        && UpperHexString::new(uppercase_hex.to_string()).is_ok()
+        && AnyCaseHexString::new(any_case_hex.to_string()).is_ok()
+    }
+  }
+
+  #[test]
+  fn it_shrinks_hex_str() {
+    let hex = UpperHexString::new("0AFF").unwrap();
+    let shrunk = hex.shrink().collect::<Vec<_>>();
+
+    assert!(shrunk.contains(&UpperHexString::default()));
+    assert!(shrunk.contains(&UpperHexString::new("FF").unwrap()));
+    assert!(shrunk.contains(&UpperHexString::new("00FF").unwrap()));
+    assert!(shrunk.iter().all(|s| s.0.len() <= hex.0.len()));
+  }
+}
@@ -0,0 +1,108 @@
+//! Random hexadecimal string generation through the `rand` crate.
+
+use ::rand::distributions::Distribution;
+use ::rand::Rng;
+
+use crate::{Case, HexString};
+
+/// Distribution of [`HexString`] representing a fixed number of random bytes.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{HexDistribution, LowerHexString};
+/// use rand::Rng;
+///
+/// let hex: LowerHexString = rand::thread_rng().sample(HexDistribution::new(16));
+///
+/// assert_eq!(hex.to_string().len(), 32);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HexDistribution {
+  byte_len: usize,
+}
+
+impl HexDistribution {
+  /// Constructs a new [`HexDistribution`] of `byte_len` bytes.
+  pub fn new(byte_len: usize) -> Self {
+    Self { byte_len }
+  }
+
+  /// Returns the number of bytes of the sampled [`HexString`].
+  pub fn byte_len(&self) -> usize {
+    self.byte_len
+  }
+}
+
+impl<const C: Case> Distribution<HexString<C>> for HexDistribution {
+  fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> HexString<C> {
+    let mut bytes = vec![0u8; self.byte_len];
+
+    rng.fill_bytes(&mut bytes);
+
+    HexString::from(bytes)
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Constructs a new [`HexString`] of `byte_len` random bytes using the thread-local random
+  /// number generator.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::UpperHexString;
+  ///
+  /// let hex = UpperHexString::random(8);
+  ///
+  /// assert_eq!(hex.to_string().len(), 16);
+  /// ```
+  pub fn random(byte_len: usize) -> Self {
+    Self::random_with_rng(&mut ::rand::thread_rng(), byte_len)
+  }
+
+  /// Constructs a new [`HexString`] of `byte_len` random bytes using the given random number
+  /// generator.
+  pub fn random_with_rng<R: Rng + ?Sized>(rng: &mut R, byte_len: usize) -> Self {
+    rng.sample(HexDistribution::new(byte_len))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::rand::rngs::StdRng;
+  use ::rand::SeedableRng;
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_generates_random_hex_str() {
+    let hex = LowerHexString::random(32);
+
+    assert_eq!(hex.0.len(), 64);
+    assert!(LowerHexString::new(hex.to_string()).is_ok());
+    assert_eq!(UpperHexString::random(0), UpperHexString::default());
+  }
+
+  #[test]
+  fn it_generates_reproducible_hex_str_from_rng() {
+    let a = UpperHexString::random_with_rng(&mut StdRng::seed_from_u64(42), 16);
+    let b = UpperHexString::random_with_rng(&mut StdRng::seed_from_u64(42), 16);
+
+    assert_eq!(a, b);
+    assert!(UpperHexString::new(a.to_string()).is_ok());
+  }
+
+  #[test]
+  fn it_samples_from_distribution() {
+    let distribution = HexDistribution::new(4);
+    let hexes: Vec<AnyCaseHexString> = StdRng::seed_from_u64(42)
+      .sample_iter(distribution)
+      .take(10)
+      .collect();
+
+    assert_eq!(distribution.byte_len(), 4);
+    assert!(hexes.iter().all(|hex| hex.0.len() == 8));
+  }
+}
@@ -0,0 +1,129 @@
+//! Parallel encoding and decoding of large inputs through the `rayon` crate.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ::rayon::prelude::*;
+
+use crate::Error;
+
+// Number of bytes processed by each parallel task.
+const CHUNK_LEN: usize = 64 * 1024;
+
+static PARALLEL_THRESHOLD: AtomicUsize = AtomicUsize::new(1024 * 1024);
+
+/// Returns the number of bytes from which encoding and decoding are performed in parallel.
+///
+/// It defaults to 1 MiB.
+pub fn parallel_threshold() -> usize {
+  PARALLEL_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Sets the number of bytes from which encoding and decoding are performed in parallel, for the
+/// whole program.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{parallel_threshold, set_parallel_threshold, LowerHexString};
+///
+/// set_parallel_threshold(2 * 1024 * 1024);
+///
+/// assert_eq!(parallel_threshold(), 2 * 1024 * 1024);
+///
+/// let hex = LowerHexString::from(vec![42u8; 4 * 1024 * 1024]);
+/// ```
+pub fn set_parallel_threshold(byte_len: usize) {
+  PARALLEL_THRESHOLD.store(byte_len, Ordering::Relaxed)
+}
+
+pub(crate) fn encode_to_slice(bytes: &[u8], out: &mut [u8], digits: &[u8; 16]) {
+  bytes
+    .par_chunks(CHUNK_LEN)
+    .zip(out.par_chunks_mut(CHUNK_LEN * 2))
+    .for_each(|(bytes, out)| crate::encode_chunk(bytes, out, digits));
+}
+
+pub(crate) fn decode_to_slice(s: &str, out: &mut [u8]) -> Result<(), Error> {
+  // mimics `hex::decode_to_slice` checks.
+  if s.len() & 1 != 0 {
+    return Err(Error::OddLength);
+  }
+
+  if s.len() != out.len() * 2 {
+    return Err(Error::InvalidStringLength);
+  }
+
+  s.as_bytes()
+    .par_chunks(CHUNK_LEN * 2)
+    .zip(out.par_chunks_mut(CHUNK_LEN))
+    .enumerate()
+    .try_for_each(|(i, (chunk, out))| {
+      hex::decode_to_slice(chunk, out).map_err(|e| match Error::from(e) {
+        Error::InvalidHexCharacter { c, index } => Error::InvalidHexCharacter {
+          c,
+          index: i * CHUNK_LEN * 2 + index,
+        },
+        e => e,
+      })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  fn bytes(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 31 + 7) as u8).collect()
+  }
+
+  #[test]
+  fn it_encodes_in_parallel() {
+    let bytes = bytes(CHUNK_LEN * 3 + 5);
+    let mut out = vec![0u8; bytes.len() * 2];
+
+    encode_to_slice(&bytes, &mut out, b"0123456789ABCDEF");
+
+    assert_eq!(out, hex::encode_upper(&bytes).into_bytes());
+  }
+
+  #[test]
+  fn it_decodes_in_parallel() {
+    let bytes = bytes(CHUNK_LEN * 3 + 5);
+    let mut out = vec![0u8; bytes.len()];
+
+    decode_to_slice(&hex::encode(&bytes), &mut out).unwrap();
+
+    assert_eq!(out, bytes);
+  }
+
+  #[test]
+  fn it_fails_to_decode_invalid_str_in_parallel() {
+    let mut s = hex::encode(bytes(CHUNK_LEN * 2));
+    let mut out = vec![0u8; CHUNK_LEN * 2];
+
+    s.replace_range(CHUNK_LEN * 3..CHUNK_LEN * 3 + 1, "z");
+
+    assert_eq!(
+      decode_to_slice(&s, &mut out),
+      Err(Error::InvalidHexCharacter {
+        c: 'z',
+        index: CHUNK_LEN * 3
+      })
+    );
+    assert_eq!(decode_to_slice("abc", &mut out), Err(Error::OddLength));
+    assert_eq!(
+      decode_to_slice("ab", &mut out),
+      Err(Error::InvalidStringLength)
+    );
+  }
+
+  #[test]
+  fn it_round_trips_large_hex_str() {
+    let bytes = bytes(parallel_threshold() + 1);
+    let hex = UpperHexString::from(&bytes[..]);
+
+    assert_eq!(hex, UpperHexString::new(hex::encode_upper(&bytes)).unwrap());
+    assert_eq!(LowerHexString::from(&bytes[..]).decode(), bytes);
+  }
+}
@@ -0,0 +1,105 @@
+//! Reading of decoded bytes through the `std::io::Read` trait.
+
+use std::io;
+
+use crate::{Case, Error, HexString};
+
+/// Reader of the decoded bytes of a [`HexString`], which are decoded on demand.
+///
+/// This struct is created by [`HexString::reader`].
+#[derive(Clone, Debug)]
+pub struct HexReader<'a> {
+  s: &'a str,
+}
+
+impl HexReader<'_> {
+  // Decodes the next bytes into the buffer, returning the number of decoded bytes.
+  pub(crate) fn read_bytes(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+    let len = buf.len().min(self.s.len() / 2);
+    let (chunk, rest) = self.s.split_at(len * 2);
+
+    crate::decode_to_slice(chunk, &mut buf[..len])?;
+    self.s = rest;
+
+    Ok(len)
+  }
+}
+
+impl io::Read for HexReader<'_> {
+  /// Reads the next decoded bytes.
+  ///
+  /// It fails with an [`io::ErrorKind::InvalidData`] error only if the [`HexString`] has been
+  /// constructed from the `new_unchecked` method with an invalid string.
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    self
+      .read_bytes(buf)
+      .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Returns a reader of the decoded bytes of the [`HexString`], without decoding them into an
+  /// intermediate buffer.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use std::io::Read;
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("deadbeef").unwrap();
+  /// let mut bytes = [0u8; 3];
+  ///
+  /// hex.reader().read_exact(&mut bytes).unwrap();
+  ///
+  /// assert_eq!(bytes, [0xde, 0xad, 0xbe]);
+  /// ```
+  pub fn reader(&self) -> HexReader<'_> {
+    HexReader { s: &self.0 }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::borrow::Cow;
+  use std::io::Read;
+
+  use super::*;
+  use crate::{AnyCaseHexString, UpperHexString};
+
+  #[test]
+  fn it_reads_decoded_bytes() {
+    let hex = AnyCaseHexString::new("DeadBeef0011").unwrap();
+    let mut reader = hex.reader();
+    let mut buf = [0u8; 4];
+
+    assert_eq!(reader.read(&mut buf).unwrap(), 4);
+    assert_eq!(buf, [0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(buf[..2], [0x00, 0x11]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+  }
+
+  #[test]
+  fn it_reads_to_end() {
+    let bytes = (0..=255).collect::<Vec<u8>>();
+    let hex = UpperHexString::from(&bytes[..]);
+    let mut read_bytes = Vec::new();
+
+    hex.reader().read_to_end(&mut read_bytes).unwrap();
+
+    assert_eq!(read_bytes, bytes);
+  }
+
+  #[test]
+  fn it_fails_to_read_invalid_unchecked_str() {
+    // bypasses the check of `new_unchecked` in debug builds.
+    let hex: UpperHexString = HexString(Cow::Borrowed("00ZZ"));
+    let mut buf = [0u8; 2];
+
+    assert_eq!(
+      hex.reader().read(&mut buf).unwrap_err().kind(),
+      io::ErrorKind::InvalidData
+    );
+  }
+}
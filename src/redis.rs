@@ -0,0 +1,115 @@
+//! Redis integration through the `redis` conversion traits.
+//!
+//! A [`HexString`] is written and read as its text, and an [`AsBlob`] as its decoded bytes.
+
+use ::redis::{ErrorKind, FromRedisValue, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+use crate::{AsBlob, Case, HexString};
+
+impl<const C: Case> ToRedisArgs for HexString<C> {
+  fn write_redis_args<W: ?Sized + RedisWrite>(&self, out: &mut W) {
+    out.write_arg(self.0.as_bytes())
+  }
+}
+
+impl<const C: Case> FromRedisValue for HexString<C> {
+  /// Reads a [`HexString`] from a bulk string or a status reply holding a hexadecimal string.
+  fn from_redis_value(value: &Value) -> RedisResult<Self> {
+    let s = match value {
+      Value::Data(bytes) => std::str::from_utf8(bytes).map_err(|_| {
+        (
+          ErrorKind::TypeError,
+          "Response was of incompatible type",
+          "Invalid UTF-8 in hexadecimal string reply".to_owned(),
+        )
+      })?,
+      Value::Status(s) => s,
+      value => {
+        return Err(
+          (
+            ErrorKind::TypeError,
+            "Response was of incompatible type",
+            format!(
+              "Response type not hexadecimal string compatible (response was {:?})",
+              value
+            ),
+          )
+            .into(),
+        )
+      }
+    };
+
+    Self::new(s.to_owned()).map_err(|e| {
+      (
+        ErrorKind::TypeError,
+        "Response was of incompatible type",
+        e.to_string(),
+      )
+        .into()
+    })
+  }
+}
+
+impl<const C: Case> ToRedisArgs for AsBlob<HexString<C>> {
+  fn write_redis_args<W: ?Sized + RedisWrite>(&self, out: &mut W) {
+    out.write_arg(&self.0.decode())
+  }
+}
+
+impl<const C: Case> FromRedisValue for AsBlob<HexString<C>> {
+  /// Reads a [`HexString`] from a bulk string holding the decoded bytes.
+  fn from_redis_value(value: &Value) -> RedisResult<Self> {
+    match value {
+      Value::Data(bytes) => Ok(Self(HexString::from(&bytes[..]))),
+      value => Err(
+        (
+          ErrorKind::TypeError,
+          "Response was of incompatible type",
+          format!(
+            "Response type not bytes compatible (response was {:?})",
+            value
+          ),
+        )
+          .into(),
+      ),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_writes_hex_str_as_text_or_bytes() {
+    let hex = LowerHexString::new("dead").unwrap();
+
+    assert_eq!(hex.to_redis_args(), vec![b"dead".to_vec()]);
+    assert_eq!(AsBlob(hex).to_redis_args(), vec![vec![0xde, 0xad]]);
+  }
+
+  #[test]
+  fn it_reads_hex_str_from_text_replies() {
+    assert_eq!(
+      UpperHexString::from_redis_value(&Value::Data(b"DEAD".to_vec())).unwrap(),
+      UpperHexString::new("DEAD").unwrap()
+    );
+    assert_eq!(
+      UpperHexString::from_redis_value(&Value::Status("BEEF".to_owned())).unwrap(),
+      UpperHexString::new("BEEF").unwrap()
+    );
+    assert!(UpperHexString::from_redis_value(&Value::Data(b"dead".to_vec())).is_err());
+    assert!(UpperHexString::from_redis_value(&Value::Data(vec![0xde, 0xad])).is_err());
+    assert!(UpperHexString::from_redis_value(&Value::Nil).is_err());
+  }
+
+  #[test]
+  fn it_reads_hex_str_from_byte_replies() {
+    assert_eq!(
+      AsBlob::<LowerHexString>::from_redis_value(&Value::Data(vec![0xde, 0xad])).unwrap(),
+      AsBlob(LowerHexString::new("dead").unwrap())
+    );
+    assert!(AsBlob::<LowerHexString>::from_redis_value(&Value::Int(42)).is_err());
+  }
+}
@@ -0,0 +1,110 @@
+//! SQLite integration through the `rusqlite` traits.
+//!
+//! A [`HexString`] is stored as text, which can be made explicit with the [`AsText`] wrapper, and
+//! an [`AsBlob`] as a blob holding the decoded bytes.
+
+use ::rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use ::rusqlite::Result;
+
+use crate::{AsBlob, AsText, Case, HexString};
+
+impl<const C: Case> ToSql for HexString<C> {
+  fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+    Ok(ToSqlOutput::Borrowed(ValueRef::Text(self.0.as_bytes())))
+  }
+}
+
+impl<const C: Case> FromSql for HexString<C> {
+  fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+    Self::new(value.as_str()?.to_owned()).map_err(|e| FromSqlError::Other(Box::new(e)))
+  }
+}
+
+impl<const C: Case> ToSql for AsText<HexString<C>> {
+  fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+    self.0.to_sql()
+  }
+}
+
+impl<const C: Case> FromSql for AsText<HexString<C>> {
+  fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+    HexString::column_result(value).map(Self)
+  }
+}
+
+impl<const C: Case> ToSql for AsBlob<HexString<C>> {
+  fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+    Ok(ToSqlOutput::from(self.0.decode()))
+  }
+}
+
+impl<const C: Case> FromSql for AsBlob<HexString<C>> {
+  fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+    value.as_blob().map(|bytes| Self(HexString::from(bytes)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::rusqlite::Connection;
+
+  use super::*;
+  use crate::{Error, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_stores_hex_str_as_text() {
+    let conn = Connection::open_in_memory().unwrap();
+    let hex = LowerHexString::new("deadbeef").unwrap();
+
+    let (value, ty): (LowerHexString, String) = conn
+      .query_row("SELECT ?1, typeof(?1)", [&hex], |row| {
+        Ok((row.get(0)?, row.get(1)?))
+      })
+      .unwrap();
+
+    assert_eq!(value, hex);
+    assert_eq!(ty, "text");
+    assert_eq!(
+      conn
+        .query_row("SELECT ?1", [AsText(hex.clone())], |row| {
+          row.get::<_, AsText<LowerHexString>>(0)
+        })
+        .unwrap(),
+      AsText(hex)
+    );
+  }
+
+  #[test]
+  fn it_stores_hex_str_as_blob() {
+    let conn = Connection::open_in_memory().unwrap();
+    let hex = AsBlob(UpperHexString::new("DEADBEEF").unwrap());
+
+    let (value, bytes, ty): (AsBlob<UpperHexString>, Vec<u8>, String) = conn
+      .query_row("SELECT ?1, ?1, typeof(?1)", [&hex], |row| {
+        Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+      })
+      .unwrap();
+
+    assert_eq!(value, hex);
+    assert_eq!(bytes, vec![0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(ty, "blob");
+  }
+
+  #[test]
+  fn it_fails_to_read_invalid_hex_str() {
+    let conn = Connection::open_in_memory().unwrap();
+
+    let error = conn
+      .query_row("SELECT 'DEAD'", [], |row| row.get::<_, LowerHexString>(0))
+      .unwrap_err();
+
+    assert!(matches!(
+      error,
+      ::rusqlite::Error::FromSqlConversionFailure(0, _, e)
+        if e.downcast_ref() == Some(&Error::InvalidCase { expected: Case::Lower, found: Case::Upper, index: 0 })
+    ));
+    assert!(conn
+      .query_row("SELECT 42", [], |row| row.get::<_, LowerHexString>(0))
+      .is_err());
+  }
+}
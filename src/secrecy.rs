@@ -0,0 +1,56 @@
+//! Secret management through the `secrecy` crate.
+
+use ::secrecy::{CloneableSecret, DebugSecret, Secret};
+
+use crate::{Case, HexString};
+
+/// Hexadecimal string holding a secret, such as a key or a token.
+///
+/// Its value is only reachable through [`ExposeSecret`](::secrecy::ExposeSecret), its debug
+/// representation is redacted and it is wiped from memory when dropped.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{LowerHexString, SecretHexString};
+/// use secrecy::ExposeSecret;
+///
+/// let key = SecretHexString::new(LowerHexString::new("deadbeef").unwrap());
+///
+/// assert_eq!(key.expose_secret().to_string(), "deadbeef");
+/// assert!(!format!("{:?}", key).contains("deadbeef"));
+/// ```
+pub type SecretHexString<const C: Case> = Secret<HexString<C>>;
+
+impl<const C: Case> CloneableSecret for HexString<C> {}
+
+impl<const C: Case> DebugSecret for HexString<C> {}
+
+#[cfg(feature = "serde")]
+impl<const C: Case> ::secrecy::SerializableSecret for HexString<C> {}
+
+#[cfg(test)]
+mod tests {
+  use ::secrecy::ExposeSecret;
+
+  use super::*;
+  use crate::UpperHexString;
+
+  #[test]
+  fn it_redacts_secret_hex_str() {
+    let key: SecretHexString<{ Case::Upper }> =
+      Secret::new(UpperHexString::new("DEADBEEF").unwrap());
+
+    assert!(!format!("{:?}", key).contains("DEADBEEF"));
+    assert_eq!(key.clone().expose_secret(), key.expose_secret());
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn it_ser_and_deser_secret_hex_str() {
+    let key: SecretHexString<{ Case::Upper }> = serde_json::from_str("\"DEADBEEF\"").unwrap();
+
+    assert_eq!(key.expose_secret().to_string(), "DEADBEEF");
+    assert_eq!(serde_json::to_string(&key).unwrap(), "\"DEADBEEF\"");
+  }
+}
@@ -0,0 +1,76 @@
+//! Redaction of sensitive values.
+
+use std::fmt;
+
+use crate::{Case, HexString};
+
+/// Wraps a [`HexString`] holding sensitive data, such as a key or a token, so that its debug
+/// representation does not leak its value.
+///
+/// The value is only reachable through [`Sensitive::expose`] or [`Sensitive::into_inner`], which
+/// makes any use of it explicit.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{LowerHexString, Sensitive};
+///
+/// let key = Sensitive(LowerHexString::new("deadbeef").unwrap());
+///
+/// assert_eq!(format!("{:?}", key), "HexString(<4 bytes redacted>)");
+/// assert_eq!(key.expose().to_string(), "deadbeef");
+/// ```
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct Sensitive<T>(pub T);
+
+impl<T> Sensitive<T> {
+  /// Returns a reference to the wrapped value.
+  pub fn expose(&self) -> &T {
+    &self.0
+  }
+
+  /// Consumes the [`Sensitive`], returning the wrapped value.
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<const C: Case> From<HexString<C>> for Sensitive<HexString<C>> {
+  fn from(s: HexString<C>) -> Self {
+    Self(s)
+  }
+}
+
+impl<const C: Case> fmt::Debug for Sensitive<HexString<C>> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "HexString(<{} bytes redacted>)", self.0 .0.len() / 2)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{AnyCaseHexString, UpperHexString};
+
+  #[test]
+  fn it_redacts_debug_representation() {
+    let key = Sensitive(UpperHexString::new("DEADBEEF0011").unwrap());
+
+    assert_eq!(format!("{:?}", key), "HexString(<6 bytes redacted>)");
+    assert_eq!(format!("{:#?}", key), "HexString(<6 bytes redacted>)");
+    assert_eq!(
+      format!("{:?}", Some(Sensitive(AnyCaseHexString::default()))),
+      "Some(HexString(<0 bytes redacted>))"
+    );
+  }
+
+  #[test]
+  fn it_exposes_wrapped_value() {
+    let hex = UpperHexString::new("DEADBEEF").unwrap();
+    let key = Sensitive::from(hex.clone());
+
+    assert_eq!(key.expose(), &hex);
+    assert_eq!(key.into_inner(), hex);
+  }
+}
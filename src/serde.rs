@@ -0,0 +1,580 @@
+//! Serde support and helpers adjusting how a [`HexString`] is serialized and deserialized.
+//!
+//! A [`HexString`] is deserialized from a hexadecimal string, or from a byte string which is
+//! encoded in lowercase. Byte strings are accepted from formats handing them over when a string
+//! is requested, such as MessagePack `bin` values.
+//!
+//! A [`HexString`] is serialized in its own case by default. The [`lowercase`] and [`uppercase`]
+//! modules normalize the case on the wire instead, and are meant to be used with the
+//! `#[serde(with = "...")]` attribute:
+//!
+//! ```
+//! use hexstring::UpperHexString;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, PartialEq, Serialize)]
+//! struct Block {
+//!   #[serde(with = "hexstring::serde::lowercase")]
+//!   hash: UpperHexString,
+//! }
+//!
+//! let block = Block { hash: UpperHexString::new("DEADBEEF").unwrap() };
+//! let json = serde_json::to_string(&block).unwrap();
+//!
+//! assert_eq!(json, r#"{"hash":"deadbeef"}"#);
+//! assert_eq!(serde_json::from_str::<Block>(&json).unwrap(), block);
+//! ```
+
+use std::fmt;
+
+use ::serde::de::{Error as DeError, Unexpected, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{AnyCaseHexString, AnyHexString, Case, Error, HexString};
+
+impl<'de, const C: Case> Deserialize<'de> for HexString<C> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_string(HexStringVisitor::<C>)
+  }
+}
+
+struct HexStringVisitor<const C: Case>;
+
+impl<const C: Case> Visitor<'_> for HexStringVisitor<C> {
+  type Value = HexString<C>;
+
+  fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("a hexadecimal string or a byte string")
+  }
+
+  fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+    HexString::new(v.to_owned()).map_err(E::custom)
+  }
+
+  fn visit_string<E: DeError>(self, v: String) -> Result<Self::Value, E> {
+    HexString::new(v).map_err(E::custom)
+  }
+
+  fn visit_bytes<E: DeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+    Ok(HexString::from(v))
+  }
+
+  fn visit_byte_buf<E: DeError>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+    Ok(HexString::from(v))
+  }
+}
+
+impl<'de> Deserialize<'de> for AnyHexString {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = AnyCaseHexString::deserialize(deserializer)?;
+
+    AnyHexString::try_from(s).map_err(D::Error::custom)
+  }
+}
+
+/// Wraps a [`HexString`] so that its deserialization accepts a string of any case, normalizing it
+/// to the expected case.
+///
+/// Serialization is left unchanged and emits the [`HexString`] in its own case.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{Lenient, LowerHexString};
+///
+/// let hex: Lenient<LowerHexString> = serde_json::from_str("\"DeadBeef\"").unwrap();
+///
+/// assert_eq!(hex.0, LowerHexString::new("deadbeef").unwrap());
+/// assert_eq!(serde_json::to_string(&hex).unwrap(), "\"deadbeef\"");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Lenient<T>(pub T);
+
+impl<T> Lenient<T> {
+  /// Consumes the [`Lenient`], returning the wrapped value.
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<const C: Case> From<HexString<C>> for Lenient<HexString<C>> {
+  fn from(s: HexString<C>) -> Self {
+    Self(s)
+  }
+}
+
+impl<const C: Case> fmt::Display for Lenient<HexString<C>> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&self.0, f)
+  }
+}
+
+impl<const C: Case> Serialize for Lenient<HexString<C>> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.0.serialize(serializer)
+  }
+}
+
+impl<'de, const C: Case> Deserialize<'de> for Lenient<HexString<C>> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    AnyCaseHexString::deserialize(deserializer).map(|s| Self(s.to_case()))
+  }
+}
+
+/// Wraps a [`HexString`] so that its deserialization rejects strings representing more than `MAX`
+/// bytes.
+///
+/// The length is checked before the string is copied and validated, which bounds the work done
+/// on untrusted input. Note that the deserializer may still buffer the whole string beforehand.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{Bounded, LowerHexString};
+///
+/// let hex: Bounded<LowerHexString, 4> = serde_json::from_str("\"deadbeef\"").unwrap();
+///
+/// assert_eq!(hex.0, LowerHexString::new("deadbeef").unwrap());
+/// assert!(serde_json::from_str::<Bounded<LowerHexString, 4>>("\"deadbeef00\"").is_err());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Bounded<T, const MAX: usize>(pub T);
+
+impl<T, const MAX: usize> Bounded<T, MAX> {
+  /// Consumes the [`Bounded`], returning the wrapped value.
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<const C: Case, const MAX: usize> TryFrom<HexString<C>> for Bounded<HexString<C>, MAX> {
+  type Error = Error;
+
+  /// Wraps the [`HexString`] if it represents at most `MAX` bytes.
+  fn try_from(s: HexString<C>) -> Result<Self, Self::Error> {
+    check_max_len(&s.0, MAX)?;
+
+    Ok(Self(s))
+  }
+}
+
+impl<const C: Case, const MAX: usize> fmt::Display for Bounded<HexString<C>, MAX> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&self.0, f)
+  }
+}
+
+impl<const C: Case, const MAX: usize> Serialize for Bounded<HexString<C>, MAX> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.0.serialize(serializer)
+  }
+}
+
+impl<'de, const C: Case, const MAX: usize> Deserialize<'de> for Bounded<HexString<C>, MAX> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer
+      .deserialize_str(BoundedVisitor::<C, MAX>)
+      .map(Self)
+  }
+}
+
+struct BoundedVisitor<const C: Case, const MAX: usize>;
+
+impl<const C: Case, const MAX: usize> Visitor<'_> for BoundedVisitor<C, MAX> {
+  type Value = HexString<C>;
+
+  fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "a hexadecimal string of at most {} bytes", MAX)
+  }
+
+  fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+    check_max_len(v, MAX).map_err(E::custom)?;
+
+    HexString::new(v.to_owned()).map_err(E::custom)
+  }
+
+  fn visit_string<E: DeError>(self, v: String) -> Result<Self::Value, E> {
+    check_max_len(&v, MAX).map_err(E::custom)?;
+
+    HexString::new(v).map_err(E::custom)
+  }
+
+  fn visit_bytes<E: DeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+    if v.len() > MAX {
+      return Err(E::invalid_length(v.len(), &self));
+    }
+
+    Ok(HexString::from(v))
+  }
+}
+
+// Checks that the given string represents at most `max` bytes, without validating it.
+fn check_max_len(s: &str, max: usize) -> Result<(), Error> {
+  let len = s.len() / 2;
+
+  if len > max {
+    return Err(Error::InvalidByteLength { len, min: 0, max });
+  }
+
+  Ok(())
+}
+
+/// Wraps a [`HexString`] so that its deserialization also accepts an unsigned integer, encoded as
+/// `N` big-endian bytes.
+///
+/// It allows configuration files to write hexadecimal values as integer literals, such as the
+/// YAML `0xDEADBEEF`. Strings are deserialized as usual, and serialization is left unchanged.
+///
+/// The deserializer must be self-describing, like JSON or YAML ones, since the type of the input
+/// is not known in advance.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{LowerHexString, Numeric};
+///
+/// let hex: Numeric<LowerHexString, 4> = serde_json::from_str("3735928559").unwrap();
+///
+/// assert_eq!(hex.0, LowerHexString::new("deadbeef").unwrap());
+///
+/// let hex: Numeric<LowerHexString, 4> = serde_json::from_str("\"0102\"").unwrap();
+///
+/// assert_eq!(hex.0, LowerHexString::new("0102").unwrap());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Numeric<T, const N: usize>(pub T);
+
+impl<T, const N: usize> Numeric<T, N> {
+  /// Consumes the [`Numeric`], returning the wrapped value.
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<const C: Case, const N: usize> From<HexString<C>> for Numeric<HexString<C>, N> {
+  fn from(s: HexString<C>) -> Self {
+    Self(s)
+  }
+}
+
+impl<const C: Case, const N: usize> fmt::Display for Numeric<HexString<C>, N> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&self.0, f)
+  }
+}
+
+impl<const C: Case, const N: usize> Serialize for Numeric<HexString<C>, N> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.0.serialize(serializer)
+  }
+}
+
+impl<'de, const C: Case, const N: usize> Deserialize<'de> for Numeric<HexString<C>, N> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer
+      .deserialize_any(NumericVisitor::<C, N>)
+      .map(Self)
+  }
+}
+
+struct NumericVisitor<const C: Case, const N: usize>;
+
+impl<const C: Case, const N: usize> Visitor<'_> for NumericVisitor<C, N> {
+  type Value = HexString<C>;
+
+  fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "a hexadecimal string or an unsigned integer of at most {} bytes",
+      N
+    )
+  }
+
+  fn visit_i64<E: DeError>(self, v: i64) -> Result<Self::Value, E> {
+    match u64::try_from(v) {
+      Ok(v) => self.visit_u64(v),
+      Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+    }
+  }
+
+  fn visit_i128<E: DeError>(self, v: i128) -> Result<Self::Value, E> {
+    match u128::try_from(v) {
+      Ok(v) => self.visit_u128(v),
+      Err(_) => Err(E::invalid_value(
+        Unexpected::Other("negative integer"),
+        &self,
+      )),
+    }
+  }
+
+  fn visit_u64<E: DeError>(self, v: u64) -> Result<Self::Value, E> {
+    self.visit_u128(u128::from(v))
+  }
+
+  fn visit_u128<E: DeError>(self, v: u128) -> Result<Self::Value, E> {
+    let bytes = v.to_be_bytes();
+    let skipped_len = bytes.len().saturating_sub(N);
+
+    if bytes[..skipped_len].iter().any(|&b| b != 0) {
+      return Err(E::invalid_value(
+        Unexpected::Other("integer too large"),
+        &self,
+      ));
+    }
+
+    let mut out = vec![0u8; N.saturating_sub(bytes.len())];
+
+    out.extend_from_slice(&bytes[skipped_len..]);
+
+    Ok(HexString::from(out))
+  }
+
+  fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+    HexStringVisitor::<C>.visit_str(v)
+  }
+
+  fn visit_string<E: DeError>(self, v: String) -> Result<Self::Value, E> {
+    HexStringVisitor::<C>.visit_string(v)
+  }
+
+  fn visit_bytes<E: DeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+    HexStringVisitor::<C>.visit_bytes(v)
+  }
+}
+
+/// Serializes a [`HexString`] in lowercase and deserializes it from a string of any case.
+pub mod lowercase {
+  use super::*;
+
+  /// Serializes the [`HexString`] as a lowercase string.
+  ///
+  /// # Errors
+  /// This function fails if the serializer fails.
+  pub fn serialize<S: Serializer, const C: Case>(
+    hex: &HexString<C>,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex.as_lowercase())
+  }
+
+  /// Deserializes a [`HexString`] from a string of any case, normalizing it to the expected case.
+  ///
+  /// # Errors
+  /// This function fails if the deserialized string is not a valid hexadecimal.
+  pub fn deserialize<'de, D: Deserializer<'de>, const C: Case>(
+    deserializer: D,
+  ) -> Result<HexString<C>, D::Error> {
+    Lenient::deserialize(deserializer).map(Lenient::into_inner)
+  }
+}
+
+/// Serializes a [`HexString`] in uppercase and deserializes it from a string of any case.
+pub mod uppercase {
+  use super::*;
+
+  /// Serializes the [`HexString`] as an uppercase string.
+  ///
+  /// # Errors
+  /// This function fails if the serializer fails.
+  pub fn serialize<S: Serializer, const C: Case>(
+    hex: &HexString<C>,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex.as_uppercase())
+  }
+
+  /// Deserializes a [`HexString`] from a string of any case, normalizing it to the expected case.
+  ///
+  /// # Errors
+  /// This function fails if the deserialized string is not a valid hexadecimal.
+  pub fn deserialize<'de, D: Deserializer<'de>, const C: Case>(
+    deserializer: D,
+  ) -> Result<HexString<C>, D::Error> {
+    Lenient::deserialize(deserializer).map(Lenient::into_inner)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::serde::de::value::{BytesDeserializer, Error as ValueError};
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_deser_hex_str_from_bytes() {
+    let bytes: &[u8] = &[0xde, 0xad];
+
+    assert_eq!(
+      LowerHexString::deserialize(BytesDeserializer::<ValueError>::new(bytes)),
+      Ok(LowerHexString::new("dead").unwrap())
+    );
+    assert_eq!(
+      UpperHexString::deserialize(BytesDeserializer::<ValueError>::new(bytes)),
+      Ok(UpperHexString::new("DEAD").unwrap())
+    );
+    assert_eq!(
+      Lenient::<UpperHexString>::deserialize(BytesDeserializer::<ValueError>::new(bytes)),
+      Ok(Lenient(UpperHexString::new("DEAD").unwrap()))
+    );
+  }
+
+  #[test]
+  fn it_fails_to_deser_hex_str_from_other_types() {
+    let error = serde_json::from_str::<LowerHexString>("42").unwrap_err();
+
+    assert!(error
+      .to_string()
+      .starts_with("invalid type: integer `42`, expected a hexadecimal string or a byte string"));
+  }
+
+  #[test]
+  fn it_ser_and_deser_any_hex_str() {
+    let hex = AnyHexString::new("DEAD").unwrap();
+
+    assert_eq!(serde_json::to_string(&hex).unwrap(), "\"DEAD\"");
+    assert_eq!(
+      serde_json::from_str::<AnyHexString>("\"DEAD\"").unwrap(),
+      hex
+    );
+    assert!(serde_json::from_str::<AnyHexString>("\"DeAd\"").is_err());
+  }
+
+  #[test]
+  fn it_deser_hex_str_of_any_case() {
+    let hex: Lenient<LowerHexString> = serde_json::from_str("\"DeadBeef\"").unwrap();
+
+    assert_eq!(hex.into_inner(), LowerHexString::new("deadbeef").unwrap());
+
+    let hex: Lenient<UpperHexString> = serde_json::from_str("\"deadbeef\"").unwrap();
+
+    assert_eq!(hex.0, UpperHexString::new("DEADBEEF").unwrap());
+
+    let hex: Lenient<AnyCaseHexString> = serde_json::from_str("\"DeadBeef\"").unwrap();
+
+    assert_eq!(hex.to_string(), "DeadBeef");
+  }
+
+  #[test]
+  fn it_fails_to_deser_invalid_hex_str() {
+    assert!(serde_json::from_str::<Lenient<LowerHexString>>("\"xyz0\"").is_err());
+    assert!(serde_json::from_str::<Lenient<LowerHexString>>("\"abc\"").is_err());
+  }
+
+  #[test]
+  fn it_ser_hex_str_in_its_case() {
+    let hex = Lenient::from(UpperHexString::new("DEADBEEF").unwrap());
+
+    assert_eq!(serde_json::to_string(&hex).unwrap(), "\"DEADBEEF\"");
+  }
+
+  #[test]
+  fn it_deser_bounded_hex_str() {
+    let hex: Bounded<UpperHexString, 2> = serde_json::from_str("\"0A0B\"").unwrap();
+
+    assert_eq!(hex.0, UpperHexString::new("0A0B").unwrap());
+    assert_eq!(
+      serde_json::from_str::<Bounded<UpperHexString, 2>>("\"\"").unwrap(),
+      Bounded(UpperHexString::default())
+    );
+    assert_eq!(
+      serde_json::from_reader::<_, Bounded<UpperHexString, 2>>(&b"\"0A0B\""[..]).unwrap(),
+      hex
+    );
+  }
+
+  #[test]
+  fn it_fails_to_deser_oversized_hex_str() {
+    let error = serde_json::from_str::<Bounded<LowerHexString, 2>>("\"0a0b0c\"").unwrap_err();
+
+    assert!(error
+      .to_string()
+      .starts_with("Invalid byte length 3, expected between 0 and 2 bytes"));
+    assert!(serde_json::from_str::<Bounded<LowerHexString, 2>>("\"0A0B\"").is_err());
+    assert!(serde_json::from_str::<Bounded<LowerHexString, 2>>("42").is_err());
+    assert!(
+      Bounded::<LowerHexString, 2>::deserialize(BytesDeserializer::<ValueError>::new(&[1, 2, 3]))
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn it_wraps_bounded_hex_str() {
+    let hex = LowerHexString::new("0a0b0c").unwrap();
+
+    assert_eq!(
+      Bounded::<_, 3>::try_from(hex.clone()).map(Bounded::into_inner),
+      Ok(hex.clone())
+    );
+    assert_eq!(
+      Bounded::<_, 2>::try_from(hex),
+      Err(Error::InvalidByteLength {
+        len: 3,
+        min: 0,
+        max: 2
+      })
+    );
+  }
+
+  #[test]
+  fn it_deser_numeric_hex_str() {
+    let hex: Numeric<UpperHexString, 4> = serde_json::from_str("3735928559").unwrap();
+
+    assert_eq!(hex.into_inner(), UpperHexString::new("DEADBEEF").unwrap());
+
+    let hex: Numeric<LowerHexString, 2> = serde_json::from_str("42").unwrap();
+
+    assert_eq!(hex.0, LowerHexString::new("002a").unwrap());
+
+    let hex: Numeric<LowerHexString, 20> = serde_json::from_str("255").unwrap();
+
+    assert_eq!(hex.to_string(), format!("{}ff", "00".repeat(19)));
+
+    let hex: Numeric<LowerHexString, 1> = serde_json::from_str("\"0a0b\"").unwrap();
+
+    assert_eq!(hex.0, LowerHexString::new("0a0b").unwrap());
+  }
+
+  #[test]
+  fn it_fails_to_deser_invalid_numeric_hex_str() {
+    let error = serde_json::from_str::<Numeric<LowerHexString, 1>>("256").unwrap_err();
+
+    assert!(error.to_string().starts_with(
+      "invalid value: integer too large, expected a hexadecimal string or an unsigned integer of \
+       at most 1 bytes"
+    ));
+    assert!(serde_json::from_str::<Numeric<LowerHexString, 4>>("-1").is_err());
+    assert!(serde_json::from_str::<Numeric<LowerHexString, 4>>("1.5").is_err());
+    assert!(serde_json::from_str::<Numeric<LowerHexString, 4>>("\"0A\"").is_err());
+    assert_eq!(
+      serde_json::to_string(&Numeric::<_, 4>::from(LowerHexString::new("0a").unwrap())).unwrap(),
+      "\"0a\""
+    );
+  }
+
+  #[derive(Debug, Deserialize, PartialEq, Serialize)]
+  struct Normalized {
+    #[serde(with = "super::lowercase")]
+    lower: UpperHexString,
+    #[serde(with = "super::uppercase")]
+    upper: AnyCaseHexString,
+  }
+
+  #[test]
+  fn it_ser_and_deser_normalized_hex_str() {
+    let normalized = Normalized {
+      lower: UpperHexString::new("0A0B").unwrap(),
+      upper: AnyCaseHexString::new("0a0B").unwrap(),
+    };
+    let json = serde_json::to_string(&normalized).unwrap();
+
+    assert_eq!(json, r#"{"lower":"0a0b","upper":"0A0B"}"#);
+    assert_eq!(
+      serde_json::from_str::<Normalized>(r#"{"lower":"0a0b","upper":"0a0B"}"#).unwrap(),
+      normalized
+    );
+  }
+}
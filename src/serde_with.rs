@@ -0,0 +1,119 @@
+//! Adapters of the `serde_with` crate.
+
+use std::convert::TryFrom;
+
+use ::serde_with::{DeserializeAs, SerializeAs};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{Case, HexString};
+
+/// Serializes any byte container as a [`HexString`] of the given case with
+/// [serde_with](https://docs.rs/serde_with).
+///
+/// Deserialization validates the hexadecimal string against the case before decoding it into the
+/// container, which fails if its length does not match, for instance for a fixed-size array.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use hexstring::{AsHex, Case};
+/// use serde::{Deserialize, Serialize};
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Account {
+///   #[serde_as(as = "AsHex<{ Case::Lower }>")]
+///   key: [u8; 4],
+///   #[serde_as(as = "Vec<AsHex<{ Case::Upper }>>")]
+///   signatures: Vec<[u8; 2]>,
+/// }
+///
+/// let account = Account {
+///   key: [0xde, 0xad, 0xbe, 0xef],
+///   signatures: vec![[0xca, 0xfe], [0xba, 0xbe]],
+/// };
+/// let json = r#"{"key":"deadbeef","signatures":["CAFE","BABE"]}"#;
+///
+/// assert_eq!(serde_json::to_string(&account).unwrap(), json);
+/// assert_eq!(serde_json::from_str::<Account>(json).unwrap(), account);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsHex<const C: Case>;
+
+impl<T, const C: Case> SerializeAs<T> for AsHex<C>
+where
+  T: AsRef<[u8]>,
+{
+  fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    HexString::<C>::from(source.as_ref()).serialize(serializer)
+  }
+}
+
+impl<'de, T, const C: Case> DeserializeAs<'de, T> for AsHex<C>
+where
+  T: TryFrom<Vec<u8>>,
+{
+  fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let bytes = HexString::<C>::deserialize(deserializer)?.decode();
+    let len = bytes.len();
+
+    T::try_from(bytes).map_err(|_| de::Error::invalid_length(len, &"a matching number of bytes"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::serde_with::serde_as;
+  use serde::{Deserialize, Serialize};
+
+  use super::*;
+
+  #[serde_as]
+  #[derive(Debug, Deserialize, PartialEq, Serialize)]
+  struct Block {
+    #[serde_as(as = "AsHex<{ Case::Upper }>")]
+    hash: [u8; 2],
+    #[serde_as(as = "AsHex<{ Case::Any }>")]
+    data: Vec<u8>,
+    #[serde_as(as = "Option<Vec<AsHex<{ Case::Lower }>>>")]
+    parents: Option<Vec<[u8; 1]>>,
+  }
+
+  #[test]
+  fn it_ser_and_deser_byte_containers() {
+    let block = Block {
+      hash: [0xde, 0xad],
+      data: vec![0xbe, 0xef],
+      parents: Some(vec![[0x0a], [0x0b]]),
+    };
+    let json = r#"{"hash":"DEAD","data":"beef","parents":["0a","0b"]}"#;
+
+    assert_eq!(serde_json::to_string(&block).unwrap(), json);
+    assert_eq!(serde_json::from_str::<Block>(json).unwrap(), block);
+    assert_eq!(
+      serde_json::from_str::<Block>(r#"{"hash":"DEAD","data":"BeEf","parents":null}"#).unwrap(),
+      Block {
+        parents: None,
+        ..block
+      }
+    );
+  }
+
+  #[test]
+  fn it_fails_to_deser_invalid_hex_str() {
+    assert!(serde_json::from_str::<Block>(r#"{"hash":"dead","data":"","parents":null}"#).is_err());
+    assert!(
+      serde_json::from_str::<Block>(r#"{"hash":"DEADBE","data":"","parents":null}"#)
+        .unwrap_err()
+        .to_string()
+        .contains("invalid length 3, expected a matching number of bytes")
+    );
+    assert!(
+      serde_json::from_str::<Block>(r#"{"hash":"DEAD","data":"","parents":["0A"]}"#).is_err()
+    );
+  }
+}
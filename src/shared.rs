@@ -0,0 +1,80 @@
+//! Shared storage of hexadecimal strings.
+
+use std::sync::Arc;
+
+use crate::{Case, CompactHexString, GenericHexString};
+
+/// Hexadecimal string stored as an `Arc<str>`, whose clones share the same string.
+///
+/// Cloning it only increments an atomic counter, which suits values cloned across threads or
+/// tasks. It provides the same guarantees as [`HexString`](crate::HexString) and converts from
+/// and into it.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use hexstring::{Case, LowerHexString, SharedHexString};
+///
+/// let hex = SharedHexString::from(LowerHexString::new("deadbeef").unwrap());
+/// let handle = std::thread::spawn({
+///   let hex = hex.clone();
+///
+///   move || hex.to_string()
+/// });
+///
+/// assert_eq!(handle.join().unwrap(), hex.as_str());
+/// ```
+pub type SharedHexString<const C: Case> = GenericHexString<C, Arc<str>>;
+
+impl<const C: Case> From<CompactHexString<C>> for SharedHexString<C> {
+  fn from(s: CompactHexString<C>) -> Self {
+    unsafe { Self::new_unchecked(Arc::from(s.into_storage())) }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::convert::TryFrom;
+
+  use super::*;
+  use crate::{Error, HexString, LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_shares_str_between_clones() {
+    let hex = SharedHexString::<{ Case::Lower }>::new("deadbeef").unwrap();
+    let other_hex = hex.clone();
+
+    assert_eq!(hex.as_str().as_ptr(), other_hex.as_str().as_ptr());
+    assert_eq!(Arc::strong_count(&hex.into_storage()), 2);
+  }
+
+  #[test]
+  fn it_constructs_from_str() {
+    assert_eq!(
+      "DEAD"
+        .parse::<SharedHexString<{ Case::Upper }>>()
+        .unwrap()
+        .as_str(),
+      "DEAD"
+    );
+    assert_eq!(SharedHexString::<{ Case::Upper }>::default().as_str(), "");
+    assert_eq!(
+      SharedHexString::<{ Case::Lower }>::new("dea"),
+      Err(Error::OddLength)
+    );
+  }
+
+  #[test]
+  fn it_converts_from_and_into_bytes_and_hex_str() {
+    let hex = SharedHexString::<{ Case::Upper }>::from([0xde, 0xad]);
+
+    assert_eq!(<[u8; 2]>::try_from(&hex), Ok([0xde, 0xad]));
+    assert_eq!(HexString::from(&hex), UpperHexString::new("DEAD").unwrap());
+    assert_eq!(
+      SharedHexString::from(CompactHexString::from(LowerHexString::new("beef").unwrap())).as_str(),
+      "beef"
+    );
+  }
+}
@@ -0,0 +1,61 @@
+//! Shared types of the database integrations.
+
+use crate::{Case, HexString};
+
+/// Wraps a [`HexString`] so that database integrations explicitly store its text, as a text column
+/// such as `TEXT` or `VARCHAR`.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{AsText, LowerHexString};
+///
+/// let hex = AsText::from(LowerHexString::new("deadbeef").unwrap());
+///
+/// assert_eq!(hex.into_inner().to_string(), "deadbeef");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct AsText<T>(pub T);
+
+impl<T> AsText<T> {
+  /// Consumes the [`AsText`], returning the wrapped value.
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<const C: Case> From<HexString<C>> for AsText<HexString<C>> {
+  fn from(s: HexString<C>) -> Self {
+    Self(s)
+  }
+}
+
+/// Wraps a [`HexString`] so that database integrations store its decoded bytes, as a binary
+/// column such as `BYTEA` or `BLOB`, instead of its text.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::{AsBlob, LowerHexString};
+///
+/// let hex = AsBlob::from(LowerHexString::new("deadbeef").unwrap());
+///
+/// assert_eq!(hex.into_inner().to_string(), "deadbeef");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct AsBlob<T>(pub T);
+
+impl<T> AsBlob<T> {
+  /// Consumes the [`AsBlob`], returning the wrapped value.
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<const C: Case> From<HexString<C>> for AsBlob<HexString<C>> {
+  fn from(s: HexString<C>) -> Self {
+    Self(s)
+  }
+}
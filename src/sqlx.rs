@@ -0,0 +1,170 @@
+//! Database integration through the `sqlx` traits.
+//!
+//! A [`HexString`] or an [`AsText`] maps to a text column, and an [`AsBlob`] to a binary column
+//! storing the decoded bytes, for any database whose driver supports strings and bytes.
+
+use ::sqlx::database::{HasArguments, HasValueRef};
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Decode, Encode, Type};
+
+use crate::{AsBlob, AsText, Case, HexString};
+
+impl<DB: Database, const C: Case> Type<DB> for HexString<C>
+where
+  String: Type<DB>,
+{
+  fn type_info() -> DB::TypeInfo {
+    <String as Type<DB>>::type_info()
+  }
+
+  fn compatible(ty: &DB::TypeInfo) -> bool {
+    <String as Type<DB>>::compatible(ty)
+  }
+}
+
+impl<'q, DB: Database, const C: Case> Encode<'q, DB> for HexString<C>
+where
+  String: Encode<'q, DB>,
+{
+  fn encode(self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+    self.into_cow().into_owned().encode(buf)
+  }
+
+  fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+    self.0.to_string().encode(buf)
+  }
+}
+
+impl<'r, DB: Database, const C: Case> Decode<'r, DB> for HexString<C>
+where
+  &'r str: Decode<'r, DB>,
+{
+  fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+    Ok(Self::new(<&str>::decode(value)?.to_owned())?)
+  }
+}
+
+impl<DB: Database, const C: Case> Type<DB> for AsText<HexString<C>>
+where
+  String: Type<DB>,
+{
+  fn type_info() -> DB::TypeInfo {
+    <HexString<C> as Type<DB>>::type_info()
+  }
+
+  fn compatible(ty: &DB::TypeInfo) -> bool {
+    <HexString<C> as Type<DB>>::compatible(ty)
+  }
+}
+
+impl<'q, DB: Database, const C: Case> Encode<'q, DB> for AsText<HexString<C>>
+where
+  String: Encode<'q, DB>,
+{
+  fn encode(self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+    self.0.encode(buf)
+  }
+
+  fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+    self.0.encode_by_ref(buf)
+  }
+}
+
+impl<'r, DB: Database, const C: Case> Decode<'r, DB> for AsText<HexString<C>>
+where
+  &'r str: Decode<'r, DB>,
+{
+  fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+    <HexString<C> as Decode<DB>>::decode(value).map(Self)
+  }
+}
+
+impl<DB: Database, const C: Case> Type<DB> for AsBlob<HexString<C>>
+where
+  Vec<u8>: Type<DB>,
+{
+  fn type_info() -> DB::TypeInfo {
+    <Vec<u8> as Type<DB>>::type_info()
+  }
+
+  fn compatible(ty: &DB::TypeInfo) -> bool {
+    <Vec<u8> as Type<DB>>::compatible(ty)
+  }
+}
+
+impl<'q, DB: Database, const C: Case> Encode<'q, DB> for AsBlob<HexString<C>>
+where
+  Vec<u8>: Encode<'q, DB>,
+{
+  fn encode(self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+    self.0.decode().encode(buf)
+  }
+
+  fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+    self.0.decode().encode(buf)
+  }
+}
+
+impl<'r, DB: Database, const C: Case> Decode<'r, DB> for AsBlob<HexString<C>>
+where
+  &'r [u8]: Decode<'r, DB>,
+{
+  fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+    Ok(Self(HexString::from(<&[u8]>::decode(value)?)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::sqlx::sqlite::SqliteConnection;
+  use ::sqlx::{Connection, Row};
+
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+      .build()
+      .unwrap()
+      .block_on(future)
+  }
+
+  #[test]
+  fn it_stores_hex_str_as_text() {
+    block_on(async {
+      let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+      let hex = LowerHexString::new("deadbeef").unwrap();
+
+      let row = ::sqlx::query("SELECT ?1 AS hex, typeof(?1) AS type")
+        .bind(hex.clone())
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+
+      assert_eq!(row.get::<LowerHexString, _>("hex"), hex);
+      assert_eq!(row.get::<AsText<LowerHexString>, _>("hex"), AsText(hex));
+      assert_eq!(row.get::<String, _>("type"), "text");
+      assert!(row.try_get::<UpperHexString, _>("hex").is_err());
+    });
+  }
+
+  #[test]
+  fn it_stores_hex_str_as_blob() {
+    block_on(async {
+      let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+      let hex = AsBlob(UpperHexString::new("DEADBEEF").unwrap());
+
+      let row = ::sqlx::query("SELECT ?1 AS hex, typeof(?1) AS type, length(?1) AS len")
+        .bind(hex.clone())
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+
+      assert_eq!(row.get::<AsBlob<UpperHexString>, _>("hex"), hex);
+      assert_eq!(row.get::<Vec<u8>, _>("hex"), vec![0xde, 0xad, 0xbe, 0xef]);
+      assert_eq!(row.get::<String, _>("type"), "blob");
+      assert_eq!(row.get::<i64, _>("len"), 4);
+    });
+  }
+}
@@ -0,0 +1,258 @@
+//! Hexadecimal strings generic over their storage.
+
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::{Case, Error, HexString};
+
+/// Storage of a [`GenericHexString`].
+///
+/// This trait is sealed so that the stored string cannot change behind the back of the
+/// [`GenericHexString`], which would break its guarantees. It is implemented by:
+/// - `String`, `Box<str>` and `Cow<'static, str>` for owned strings
+/// - `&str` for borrowed strings, such as strings allocated in an arena
+/// - `Arc<str>` and `Rc<str>` for shared strings
+/// - `CompactString` if the **compact_str** feature is enabled
+/// - `ArrayString<N>` if the **arrayvec** feature is enabled
+pub trait Storage: sealed::Sealed {
+  /// Returns the stored string.
+  fn as_str(&self) -> &str;
+}
+
+mod sealed {
+  pub trait Sealed {}
+}
+
+macro_rules! impl_storage {
+  ($($ty:ty),* $(,)?) => {
+    $(
+      impl sealed::Sealed for $ty {}
+
+      impl Storage for $ty {
+        fn as_str(&self) -> &str {
+          self
+        }
+      }
+    )*
+  };
+}
+
+impl_storage!(String, Box<str>, Cow<'static, str>, Arc<str>, Rc<str>);
+
+impl sealed::Sealed for &str {}
+
+impl Storage for &str {
+  fn as_str(&self) -> &str {
+    self
+  }
+}
+
+#[cfg(feature = "compact_str")]
+impl_storage!(compact_str::CompactString);
+
+#[cfg(feature = "arrayvec")]
+impl<const N: usize> sealed::Sealed for arrayvec::ArrayString<N> {}
+
+#[cfg(feature = "arrayvec")]
+impl<const N: usize> Storage for arrayvec::ArrayString<N> {
+  fn as_str(&self) -> &str {
+    self
+  }
+}
+
+/// Hexadecimal string of the expected case stored in any [`Storage`].
+///
+/// It provides the same guarantees as [`HexString`] and converts from and into it, while letting
+/// the user choose the allocation strategy which suits its deployment.
+///
+/// # Examples
+///
+/// ```
+/// # #![allow(incomplete_features)]
+/// # #![feature(adt_const_params)]
+/// use std::rc::Rc;
+/// use hexstring::{Case, GenericHexString, LowerHexString};
+///
+/// let hex = GenericHexString::<{ Case::Lower }, Rc<str>>::new("deadbeef").unwrap();
+///
+/// assert_eq!(hex.clone().as_str(), "deadbeef");
+/// assert_eq!(LowerHexString::from(&hex), LowerHexString::new("deadbeef").unwrap());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct GenericHexString<const C: Case, S: Storage>(S);
+
+impl<const C: Case, S: Storage> GenericHexString<C, S> {
+  /// Constructs a new [`GenericHexString`] from a string.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hexadecimal of the expected case.
+  pub fn new<T: Into<S>>(s: T) -> Result<Self, Error> {
+    let s = s.into();
+
+    HexString::<C>::validate(s.as_str())?;
+
+    Ok(Self(s))
+  }
+
+  /// Creates a new [`GenericHexString`] without checking the string.
+  ///
+  /// The string is still checked in debug builds, or in all builds if the **paranoid** feature is
+  /// enabled.
+  ///
+  /// # Safety
+  /// The string should be a valid hexadecimal string of the expected case.
+  ///
+  /// # Panics
+  /// This method panics if the string is not a valid hexadecimal string, in debug builds or if the
+  /// **paranoid** feature is enabled.
+  pub unsafe fn new_unchecked<T: Into<S>>(s: T) -> Self {
+    let s = s.into();
+
+    HexString::<C>::check_unchecked(s.as_str());
+
+    Self(s)
+  }
+
+  /// Returns the hexadecimal string.
+  pub fn as_str(&self) -> &str {
+    self.0.as_str()
+  }
+
+  /// Consumes the [`GenericHexString`], returning the underlying storage.
+  pub fn into_storage(self) -> S {
+    self.0
+  }
+}
+
+impl<const C: Case, S: Storage> fmt::Display for GenericHexString<C, S> {
+  /// Formats the [`GenericHexString`] the same way as a [`HexString`].
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    crate::fmt_hex_str(self.as_str(), f)
+  }
+}
+
+impl<const C: Case, S> FromStr for GenericHexString<C, S>
+where
+  S: Storage + for<'a> From<&'a str>,
+{
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::new(s)
+  }
+}
+
+impl<const C: Case, S: Storage + From<String>> From<&[u8]> for GenericHexString<C, S> {
+  fn from(bytes: &[u8]) -> Self {
+    Self::from(HexString::<C>::from(bytes))
+  }
+}
+
+impl<const C: Case, S: Storage + From<String>, const N: usize> From<[u8; N]>
+  for GenericHexString<C, S>
+{
+  fn from(bytes: [u8; N]) -> Self {
+    Self::from(&bytes[..])
+  }
+}
+
+impl<const C: Case, S: Storage, const N: usize> TryFrom<&GenericHexString<C, S>> for [u8; N] {
+  type Error = Error;
+
+  fn try_from(s: &GenericHexString<C, S>) -> Result<Self, Self::Error> {
+    let mut bytes = [0u8; N];
+
+    hex::decode_to_slice(s.as_str(), &mut bytes)?;
+
+    Ok(bytes)
+  }
+}
+
+impl<const C: Case, S: Storage + From<String>> From<HexString<C>> for GenericHexString<C, S> {
+  fn from(s: HexString<C>) -> Self {
+    Self(S::from(s.into_cow().into_owned()))
+  }
+}
+
+impl<const C: Case, S: Storage> From<&GenericHexString<C, S>> for HexString<C> {
+  /// Copies the stored string into a [`HexString`].
+  fn from(s: &GenericHexString<C, S>) -> Self {
+    unsafe { HexString::new_unchecked(s.as_str().to_owned()) }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_constructs_from_any_storage() {
+    let hex = GenericHexString::<{ Case::Lower }, String>::new("dead").unwrap();
+    let other_hex = GenericHexString::<{ Case::Lower }, Rc<str>>::new("dead").unwrap();
+
+    assert_eq!(hex.as_str(), other_hex.as_str());
+    assert_eq!(hex.into_storage(), String::from("dead"));
+    assert_eq!(
+      GenericHexString::<{ Case::Upper }, Cow<'static, str>>::new("dead"),
+      Err(Error::InvalidCase {
+        expected: Case::Upper,
+        found: Case::Lower,
+        index: 0
+      })
+    );
+    assert_eq!(
+      "BEEF"
+        .parse::<GenericHexString<{ Case::Upper }, Box<str>>>()
+        .unwrap()
+        .to_string(),
+      "BEEF"
+    );
+  }
+
+  #[test]
+  fn it_converts_from_and_into_bytes_and_hex_str() {
+    let hex = GenericHexString::<{ Case::Upper }, Rc<str>>::from([0xde, 0xad]);
+
+    assert_eq!(<[u8; 2]>::try_from(&hex), Ok([0xde, 0xad]));
+    assert_eq!(
+      UpperHexString::from(&hex),
+      UpperHexString::new("DEAD").unwrap()
+    );
+    assert_eq!(
+      GenericHexString::<{ Case::Lower }, String>::from(LowerHexString::new("beef").unwrap())
+        .as_str(),
+      "beef"
+    );
+  }
+
+  #[cfg(feature = "compact_str")]
+  #[test]
+  fn it_stores_into_compact_str() {
+    let hex = GenericHexString::<{ Case::Lower }, compact_str::CompactString>::from([0xde, 0xad]);
+
+    assert_eq!(hex.as_str(), "dead");
+    assert!(!hex.into_storage().is_heap_allocated());
+  }
+
+  #[cfg(feature = "arrayvec")]
+  #[test]
+  fn it_stores_into_array_str() {
+    use arrayvec::ArrayString;
+
+    type ArrayHexString<const N: usize> = GenericHexString<{ Case::Lower }, ArrayString<N>>;
+
+    let hex = ArrayHexString::<8>::new(ArrayString::from("deadbeef").unwrap()).unwrap();
+
+    assert_eq!(<[u8; 4]>::try_from(&hex), Ok([0xde, 0xad, 0xbe, 0xef]));
+    assert_eq!(
+      ArrayHexString::<2>::new(ArrayString::from("zz").unwrap()),
+      Err(Error::InvalidHexCharacter { c: 'z', index: 0 })
+    );
+  }
+}
@@ -0,0 +1,82 @@
+//! Asynchronous streaming of decoded bytes through the `futures` traits.
+
+use bytes::{Bytes, BytesMut};
+use futures_util::stream::{self, Stream};
+
+use crate::{Case, HexString};
+
+impl<const C: Case> HexString<C> {
+  /// Converts the [`HexString`] into a stream of its decoded bytes, by chunks of at most
+  /// `chunk_size` bytes.
+  ///
+  /// Each chunk is decoded lazily, when polled. The [`HexString`] is streamed as a single chunk if
+  /// `chunk_size` is zero.
+  ///
+  /// Note that polling the stream may panic if the [`HexString`] has been constructed from the
+  /// `new_unchecked` method with an invalid string.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use futures_util::{FutureExt, StreamExt};
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::new("deadbeef00").unwrap();
+  /// let chunks = hex.into_byte_stream(2).collect::<Vec<_>>().now_or_never().unwrap();
+  ///
+  /// assert_eq!(chunks, vec![&[0xde, 0xad][..], &[0xbe, 0xef], &[0x00]]);
+  /// ```
+  pub fn into_byte_stream(self, chunk_size: usize) -> impl Stream<Item = Bytes> + Send + 'static {
+    let chunk_len = match chunk_size.saturating_mul(2) {
+      0 => usize::MAX,
+      len => len,
+    };
+    let mut position = 0;
+
+    stream::iter(std::iter::from_fn(move || {
+      if position >= self.0.len() {
+        return None;
+      }
+
+      let end = position.saturating_add(chunk_len).min(self.0.len());
+      let mut bytes = BytesMut::zeroed((end - position) / 2);
+
+      crate::decode_to_slice(&self.0[position..end], &mut bytes).unwrap();
+      position = end;
+
+      Some(bytes.freeze())
+    }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use futures_util::{FutureExt, StreamExt};
+
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  fn collect<const C: Case>(hex: HexString<C>, chunk_size: usize) -> Vec<Bytes> {
+    hex
+      .into_byte_stream(chunk_size)
+      .collect()
+      .now_or_never()
+      .unwrap()
+  }
+
+  #[test]
+  fn it_streams_decoded_bytes() {
+    let hex = UpperHexString::new("DEADBEEF0011").unwrap();
+
+    assert_eq!(
+      collect(hex.clone(), 4),
+      vec![&[0xde, 0xad, 0xbe, 0xef][..], &[0x00, 0x11]]
+    );
+    assert_eq!(
+      collect(hex.clone(), 0),
+      vec![&[0xde, 0xad, 0xbe, 0xef, 0x00, 0x11][..]]
+    );
+    assert_eq!(collect(hex, usize::MAX).len(), 1);
+    assert!(collect(LowerHexString::default(), 1).is_empty());
+  }
+}
@@ -0,0 +1,53 @@
+//! Method-call conversion of byte containers into hexadecimal string.
+
+use crate::{LowerHexString, UpperHexString};
+
+/// Extension trait encoding any byte container into a [`HexString`](crate::HexString).
+///
+/// It is implemented for every type which implements `AsRef<[u8]>`, such as `&[u8]`, `Vec<u8>`,
+/// `[u8; N]` or `bytes::Bytes`, and offers a chainable alternative to the `From` conversions.
+///
+/// # Examples
+///
+/// ```
+/// use hexstring::ToHexString;
+///
+/// assert_eq!(vec![0xde, 0xad].to_hex_lower().to_string(), "dead");
+/// assert_eq!([0xbe, 0xef].to_hex_upper().to_string(), "BEEF");
+/// ```
+pub trait ToHexString {
+  /// Encodes the bytes into a [`LowerHexString`].
+  fn to_hex_lower(&self) -> LowerHexString;
+
+  /// Encodes the bytes into an [`UpperHexString`].
+  fn to_hex_upper(&self) -> UpperHexString;
+}
+
+impl<T: AsRef<[u8]> + ?Sized> ToHexString for T {
+  fn to_hex_lower(&self) -> LowerHexString {
+    LowerHexString::from(self.as_ref())
+  }
+
+  fn to_hex_upper(&self) -> UpperHexString {
+    UpperHexString::from(self.as_ref())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_encodes_byte_containers() {
+    let bytes = vec![0x0a, 0xbc, 0x42];
+
+    assert_eq!(bytes.to_hex_lower(), LowerHexString::new("0abc42").unwrap());
+    assert_eq!(
+      bytes[..].to_hex_upper(),
+      UpperHexString::new("0ABC42").unwrap()
+    );
+    assert_eq!([0xffu8; 2].to_hex_lower().to_string(), "ffff");
+    assert_eq!(b"hi".to_hex_upper().to_string(), "6869");
+    assert_eq!(Vec::<u8>::new().to_hex_lower().to_string(), "");
+  }
+}
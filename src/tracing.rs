@@ -0,0 +1,110 @@
+//! Typed `tracing` fields.
+//!
+//! `tracing` records [`Valuable`](::valuable::Valuable) values only when built with the
+//! `tracing_unstable` cfg flag, so the fields fall back to their `Display` implementation
+//! otherwise.
+
+use ::tracing::field::Value;
+
+use crate::{Abbreviated, Case, HexString};
+
+impl<const C: Case> HexString<C> {
+  /// Returns a value recording the [`HexString`] in `tracing` spans and events.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let tx_id = LowerHexString::new("deadbeef").unwrap();
+  ///
+  /// tracing::info!(tx_id = tx_id.as_field(), "transaction received");
+  /// ```
+  pub fn as_field(&self) -> impl Value + '_ {
+    field(self)
+  }
+}
+
+impl Abbreviated<'_> {
+  /// Returns a value recording the [`Abbreviated`] in `tracing` spans and events.
+  pub fn as_field(&self) -> impl Value + '_ {
+    field(self)
+  }
+}
+
+#[cfg(tracing_unstable)]
+fn field<T: ::valuable::Valuable>(value: &T) -> impl Value + '_ {
+  ::tracing::field::valuable(value)
+}
+
+#[cfg(not(tracing_unstable))]
+fn field<T: std::fmt::Display>(value: &T) -> impl Value + '_ {
+  ::tracing::field::display(value)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fmt;
+  use std::sync::{Arc, Mutex};
+
+  use ::tracing::field::{Field, Visit};
+  use ::tracing::span::{Attributes, Id, Record};
+  use ::tracing::{Event, Metadata, Subscriber};
+
+  use crate::LowerHexString;
+
+  // Subscriber collecting the debug representation of the fields of each event.
+  #[derive(Clone, Default)]
+  struct Collector(Arc<Mutex<Vec<(&'static str, String)>>>);
+
+  impl Visit for Collector {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+      self
+        .0
+        .lock()
+        .unwrap()
+        .push((field.name(), format!("{:?}", value)));
+    }
+  }
+
+  impl Subscriber for Collector {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+      true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+      Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+      event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+  }
+
+  #[test]
+  fn it_records_fields() {
+    let hex = LowerHexString::new("deadbeef0011").unwrap();
+    let collector = Collector::default();
+
+    ::tracing::subscriber::with_default(collector.clone(), || {
+      ::tracing::info!(
+        hex = hex.as_field(),
+        short = hex.abbreviated(1, 1).as_field(),
+        "recorded"
+      );
+    });
+
+    let fields = collector.0.lock().unwrap();
+
+    assert!(fields.contains(&("hex", "deadbeef0011".to_string())));
+    assert!(fields.contains(&("short", "de…11".to_string())));
+  }
+}
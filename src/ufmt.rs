@@ -0,0 +1,43 @@
+//! Allocation-free formatting through the `ufmt` traits.
+
+use ::ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::{Case, HexString};
+
+impl<const C: Case> uDisplay for HexString<C> {
+  /// Formats the [`HexString`] as its text, ignoring the formatting flags unsupported by `ufmt`.
+  fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+    f.write_str(&self.0)
+  }
+}
+
+impl<const C: Case> uDebug for HexString<C> {
+  /// Formats the [`HexString`] the same way as its `Debug` implementation.
+  fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+    // `ufmt` does not implement `uDebug` for `str`, while hexadecimal digits never need escaping.
+    f.write_str("HexString(\"")?;
+    f.write_str(&self.0)?;
+    f.write_str("\")")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ::ufmt::uwrite;
+
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_formats_hex_str() {
+    let hex = LowerHexString::new("deadbeef").unwrap();
+    let mut s = String::new();
+
+    uwrite!(s, "{} {:?}", hex, UpperHexString::new("DEAD").unwrap()).unwrap();
+
+    assert_eq!(s, "deadbeef HexString(\"DEAD\")");
+    assert_eq!(
+      format!("{:?}", UpperHexString::new("DEAD").unwrap()),
+      "HexString(\"DEAD\")"
+    );
+  }
+}
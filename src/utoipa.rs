@@ -0,0 +1,124 @@
+//! OpenAPI schemas through the `utoipa` traits.
+//!
+//! Hexadecimal types are described as strings of format `hex`, along with a pattern matching their
+//! case and the length of fixed-size types.
+
+use std::borrow::Cow;
+
+use ::utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaFormat, Type};
+use ::utoipa::openapi::RefOr;
+use ::utoipa::{PartialSchema, ToSchema};
+
+use crate::{Case, HexString, InlineHexString};
+
+// Builds the schema of a hexadecimal string of the given case, whose length is expressed in
+// characters.
+#[doc(hidden)]
+pub fn hex_schema(case: Case, min_len: Option<usize>, max_len: Option<usize>) -> RefOr<Schema> {
+  let (pattern, description) = match case {
+    Case::Lower => ("^([0-9a-f]{2})*$", "Lowercase hexadecimal string"),
+    Case::Upper => ("^([0-9A-F]{2})*$", "Uppercase hexadecimal string"),
+    Case::Any => ("^([0-9a-fA-F]{2})*$", "Hexadecimal string"),
+  };
+
+  ObjectBuilder::new()
+    .schema_type(Type::String)
+    .format(Some(SchemaFormat::Custom("hex".to_owned())))
+    .pattern(Some(pattern))
+    .min_length(min_len)
+    .max_length(max_len)
+    .description(Some(description))
+    .build()
+    .into()
+}
+
+fn case_name(case: Case) -> &'static str {
+  match case {
+    Case::Lower => "Lower",
+    Case::Upper => "Upper",
+    Case::Any => "AnyCase",
+  }
+}
+
+impl<const C: Case> PartialSchema for HexString<C> {
+  fn schema() -> RefOr<Schema> {
+    hex_schema(C, None, None)
+  }
+}
+
+impl<const C: Case> ToSchema for HexString<C> {
+  /// Names the schema after the alias of the case, such as `LowerHexString`.
+  fn name() -> Cow<'static, str> {
+    Cow::Owned(format!("{}HexString", case_name(C)))
+  }
+}
+
+impl<const C: Case, const N: usize> PartialSchema for InlineHexString<C, N> {
+  fn schema() -> RefOr<Schema> {
+    // the string always holds an even number of characters.
+    hex_schema(C, None, Some(N & !1))
+  }
+}
+
+impl<const C: Case, const N: usize> ToSchema for InlineHexString<C, N> {
+  /// Names the schema after the case and the capacity, such as `LowerInlineHexString64`.
+  fn name() -> Cow<'static, str> {
+    Cow::Owned(format!("{}InlineHexString{}", case_name(C), N))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+  use crate::{AnyCaseHexString, LowerHexString, UpperHexString};
+
+  crate::define_hex_type!(TxHash, 4, Upper);
+
+  #[test]
+  fn it_describes_hex_str() {
+    assert_eq!(
+      serde_json::to_value(LowerHexString::schema()).unwrap(),
+      json!({
+        "type": "string",
+        "format": "hex",
+        "pattern": "^([0-9a-f]{2})*$",
+        "description": "Lowercase hexadecimal string",
+      })
+    );
+    assert_eq!(LowerHexString::name(), "LowerHexString");
+    assert_eq!(UpperHexString::name(), "UpperHexString");
+    assert_eq!(AnyCaseHexString::name(), "AnyCaseHexString");
+  }
+
+  #[test]
+  fn it_describes_fixed_size_hex_str() {
+    assert_eq!(
+      serde_json::to_value(InlineHexString::<{ Case::Any }, 9>::schema()).unwrap(),
+      json!({
+        "type": "string",
+        "format": "hex",
+        "pattern": "^([0-9a-fA-F]{2})*$",
+        "maxLength": 8,
+        "description": "Hexadecimal string",
+      })
+    );
+    assert_eq!(
+      InlineHexString::<{ Case::Lower }, 64>::name(),
+      "LowerInlineHexString64"
+    );
+    assert_eq!(
+      serde_json::to_value(TxHash::schema()).unwrap(),
+      json!({
+        "type": "string",
+        "format": "hex",
+        "pattern": "^([0-9A-F]{2})*$",
+        "minLength": 8,
+        "maxLength": 8,
+        "description": "Uppercase hexadecimal string",
+      })
+    );
+    assert_eq!(TxHash::name(), "TxHash");
+  }
+}
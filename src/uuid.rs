@@ -0,0 +1,154 @@
+//! UUID interoperability.
+
+use std::convert::{From, TryFrom};
+
+use ::uuid::Uuid;
+
+use crate::{Case, Error, HexString};
+
+// Positions of the hyphens in the hyphenated form, such as `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+impl<const C: Case> HexString<C> {
+  /// Constructs a new [`HexString`] from a hyphenated UUID string, such as
+  /// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+  ///
+  /// # Errors
+  /// This method fails if the given string is not a valid hyphenated UUID of the expected case.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let hex = LowerHexString::from_hyphenated_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+  ///
+  /// assert_eq!(hex.to_string(), "67e5504410b1426f9247bb680e5fe0c8");
+  /// ```
+  pub fn from_hyphenated_uuid(s: &str) -> Result<Self, Error> {
+    if s.len() != 36 {
+      return Err(Error::InvalidStringLength);
+    }
+
+    let mut hex = String::with_capacity(32);
+
+    for (index, c) in s.char_indices() {
+      match (HYPHENS.contains(&index), c) {
+        (true, '-') => {}
+        (true, c) | (false, c @ '-') => return Err(Error::InvalidHexCharacter { c, index }),
+        (false, c) => hex.push(c),
+      }
+    }
+
+    Self::new(hex).map_err(|e| {
+      // shifts the index by the number of hyphens stripped before the invalid character.
+      e.map_index(|index| {
+        index
+          + HYPHENS
+            .iter()
+            .enumerate()
+            .filter(|&(k, &i)| i - k <= index)
+            .count()
+      })
+    })
+  }
+
+  /// Formats the [`HexString`] as a hyphenated UUID string, such as
+  /// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+  ///
+  /// # Errors
+  /// This method fails if the [`HexString`] does not represent exactly 16 bytes.
+  pub fn to_hyphenated_uuid(&self) -> Result<String, Error> {
+    let uuid = Uuid::try_from(self.clone())?;
+
+    Ok(match C {
+      Case::Upper => format!("{:X}", uuid.hyphenated()),
+      Case::Lower | Case::Any => format!("{:x}", uuid.hyphenated()),
+    })
+  }
+}
+
+impl<const C: Case> From<Uuid> for HexString<C> {
+  fn from(uuid: Uuid) -> Self {
+    Self::from(&uuid.as_bytes()[..])
+  }
+}
+
+impl<const C: Case> TryFrom<HexString<C>> for Uuid {
+  type Error = Error;
+
+  fn try_from(s: HexString<C>) -> Result<Self, Self::Error> {
+    <[u8; 16]>::try_from(s).map(Uuid::from_bytes)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+  #[test]
+  fn it_converts_from_and_into_uuid() {
+    let uuid = Uuid::parse_str(UUID).unwrap();
+    let hex = UpperHexString::from(uuid);
+
+    assert_eq!(
+      hex,
+      UpperHexString::new("67E5504410B1426F9247BB680E5FE0C8").unwrap()
+    );
+    assert_eq!(Uuid::try_from(hex), Ok(uuid));
+    assert_eq!(
+      Uuid::try_from(LowerHexString::new("67e5").unwrap()),
+      Err(Error::InvalidStringLength)
+    );
+  }
+
+  #[test]
+  fn it_constructs_from_hyphenated_uuid() {
+    assert_eq!(
+      LowerHexString::from_hyphenated_uuid(UUID),
+      LowerHexString::new("67e5504410b1426f9247bb680e5fe0c8")
+    );
+    assert_eq!(
+      LowerHexString::from_hyphenated_uuid("67e5504410b1426f9247bb680e5fe0c8"),
+      Err(Error::InvalidStringLength)
+    );
+    assert_eq!(
+      LowerHexString::from_hyphenated_uuid("67e55044-10b1-426f-9247+bb680e5fe0c8"),
+      Err(Error::InvalidHexCharacter { c: '+', index: 23 })
+    );
+    assert_eq!(
+      LowerHexString::from_hyphenated_uuid("67e55044-10b1-426f-9247-bb680e5fE0c8"),
+      Err(Error::InvalidCase {
+        expected: Case::Lower,
+        found: Case::Upper,
+        index: 32
+      })
+    );
+    assert_eq!(
+      UpperHexString::from_hyphenated_uuid(UUID),
+      Err(Error::InvalidCase {
+        expected: Case::Upper,
+        found: Case::Lower,
+        index: 2
+      })
+    );
+  }
+
+  #[test]
+  fn it_formats_hyphenated_uuid() {
+    let hex = LowerHexString::new("67e5504410b1426f9247bb680e5fe0c8").unwrap();
+
+    assert_eq!(hex.to_hyphenated_uuid().unwrap(), UUID);
+    assert_eq!(
+      hex.to_uppercase().to_hyphenated_uuid().unwrap(),
+      UUID.to_uppercase()
+    );
+    assert_eq!(
+      LowerHexString::new("67e5").unwrap().to_hyphenated_uuid(),
+      Err(Error::InvalidStringLength)
+    );
+  }
+}
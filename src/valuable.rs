@@ -0,0 +1,79 @@
+//! Structured inspection through the `valuable` traits.
+
+use ::valuable::{Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit};
+
+use crate::{Abbreviated, Case, HexString};
+
+impl<const C: Case> Valuable for HexString<C> {
+  fn as_value(&self) -> Value<'_> {
+    Value::String(&self.0)
+  }
+
+  fn visit(&self, visit: &mut dyn Visit) {
+    visit.visit_value(self.as_value());
+  }
+}
+
+static ABBREVIATED_FIELDS: &[NamedField<'static>] = &[
+  NamedField::new("head"),
+  NamedField::new("tail"),
+  NamedField::new("byte_len"),
+];
+
+impl Valuable for Abbreviated<'_> {
+  /// Inspects the [`Abbreviated`] as a structure holding its head, its tail and the number of
+  /// bytes of the whole string, or as the whole string if it is short enough to be displayed.
+  fn as_value(&self) -> Value<'_> {
+    match self.split() {
+      Some(_) => Value::Structable(self),
+      None => Value::String(self.as_str()),
+    }
+  }
+
+  fn visit(&self, visit: &mut dyn Visit) {
+    match self.split() {
+      Some((head, tail)) => visit.visit_named_fields(&NamedValues::new(
+        ABBREVIATED_FIELDS,
+        &[
+          Value::String(head),
+          Value::String(tail),
+          Value::Usize(self.as_str().len() / 2),
+        ],
+      )),
+      None => visit.visit_value(self.as_value()),
+    }
+  }
+}
+
+impl Structable for Abbreviated<'_> {
+  fn definition(&self) -> StructDef<'_> {
+    StructDef::new_static("Abbreviated", Fields::Named(ABBREVIATED_FIELDS))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_inspects_hex_str() {
+    let hex = UpperHexString::new("DEADBEEF").unwrap();
+
+    assert!(matches!(hex.as_value(), Value::String("DEADBEEF")));
+  }
+
+  #[test]
+  fn it_inspects_abbreviated_hex_str() {
+    let hex = LowerHexString::new("deadbeef0011").unwrap();
+
+    assert_eq!(
+      format!("{:?}", hex.abbreviated(2, 1).as_value()),
+      "Abbreviated { head: \"dead\", tail: \"11\", byte_len: 6 }"
+    );
+    assert!(matches!(
+      hex.abbreviated(3, 3).as_value(),
+      Value::String("deadbeef0011")
+    ));
+  }
+}
@@ -0,0 +1,135 @@
+//! JavaScript bindings through `wasm-bindgen`.
+//!
+//! The [`JsHexString`] class is exported to JavaScript as `HexString` and holds a hexadecimal
+//! string of any case, since const generics cannot cross the JavaScript boundary.
+
+use ::js_sys::Uint8Array;
+use ::wasm_bindgen::prelude::*;
+
+use crate::{AnyCaseHexString, Case, HexString};
+
+/// Hexadecimal string of any case exported to JavaScript as `HexString`.
+///
+/// # Examples
+///
+/// ```js
+/// import { HexString } from "./pkg/hexstring.js";
+///
+/// const hex = new HexString("deadbeef");
+///
+/// console.log(hex.toBytes()); // Uint8Array [222, 173, 190, 239]
+/// console.log(HexString.fromBytes(new Uint8Array([1, 2])).toString()); // "0102"
+/// console.log(HexString.isValid("xyz")); // false
+/// ```
+#[wasm_bindgen(js_name = HexString)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JsHexString(AnyCaseHexString);
+
+#[wasm_bindgen(js_class = HexString)]
+impl JsHexString {
+  /// Constructs a new [`JsHexString`] from a string.
+  ///
+  /// # Errors
+  /// This method throws if the given string is not a valid hexadecimal string.
+  #[wasm_bindgen(constructor)]
+  pub fn new(s: String) -> Result<JsHexString, JsError> {
+    Ok(Self(AnyCaseHexString::new(s)?))
+  }
+
+  /// Constructs a new [`JsHexString`] in lowercase from a `Uint8Array`.
+  #[wasm_bindgen(js_name = fromBytes)]
+  pub fn from_bytes(bytes: &[u8]) -> JsHexString {
+    Self(AnyCaseHexString::from(bytes))
+  }
+
+  /// Checks whether the given string is a valid hexadecimal string.
+  #[wasm_bindgen(js_name = isValid)]
+  pub fn is_valid(s: &str) -> bool {
+    AnyCaseHexString::validate(s).is_ok()
+  }
+
+  /// Returns the decoded bytes of the [`JsHexString`] as a `Uint8Array`.
+  ///
+  /// # Errors
+  /// This method throws if the [`JsHexString`] does not hold a valid hexadecimal string.
+  #[wasm_bindgen(js_name = toBytes)]
+  pub fn to_bytes(&self) -> Result<Vec<u8>, JsError> {
+    let mut bytes = vec![0u8; self.0 .0.len() / 2];
+
+    crate::decode_to_slice(&self.0 .0, &mut bytes)?;
+
+    Ok(bytes)
+  }
+
+  /// Returns the hexadecimal string.
+  #[wasm_bindgen(js_name = toString)]
+  pub fn to_js_string(&self) -> String {
+    self.0.to_string()
+  }
+}
+
+impl<const C: Case> From<HexString<C>> for JsHexString {
+  fn from(s: HexString<C>) -> Self {
+    Self(s.to_case())
+  }
+}
+
+impl From<JsHexString> for AnyCaseHexString {
+  fn from(s: JsHexString) -> Self {
+    s.0
+  }
+}
+
+impl<const C: Case> From<HexString<C>> for JsValue {
+  fn from(s: HexString<C>) -> Self {
+    JsValue::from_str(&s.0)
+  }
+}
+
+impl<const C: Case> TryFrom<JsValue> for HexString<C> {
+  type Error = JsValue;
+
+  /// Converts a JavaScript string or `Uint8Array` into a [`HexString`].
+  fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+    if let Some(s) = value.as_string() {
+      return Self::new(s).map_err(|e| JsError::from(e).into());
+    }
+
+    match value.dyn_into::<Uint8Array>() {
+      Ok(bytes) => Ok(Self::from(bytes.to_vec())),
+      Err(value) => {
+        Err(JsError::new(&format!("Expected string or Uint8Array, got {value:?}")).into())
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_constructs_from_str_and_bytes() {
+    let hex = JsHexString::new("DeadBeef".to_owned()).unwrap();
+
+    assert_eq!(hex.to_js_string(), "DeadBeef");
+    assert_eq!(hex.to_bytes().unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(JsHexString::from_bytes(&[1, 2]).to_js_string(), "0102");
+    assert_eq!(
+      JsHexString::from(UpperHexString::new("0102").unwrap()),
+      JsHexString::new("0102".to_owned()).unwrap()
+    );
+  }
+
+  #[test]
+  fn it_validates_str() {
+    assert!(JsHexString::is_valid("deadBEEF"));
+    assert!(!JsHexString::is_valid("abc"));
+    assert!(!JsHexString::is_valid("xyz0"));
+    assert_eq!(
+      AnyCaseHexString::from(JsHexString::from(LowerHexString::new("0a").unwrap())).to_string(),
+      "0a"
+    );
+  }
+}
@@ -0,0 +1,74 @@
+//! Wiping of hexadecimal strings from memory through the `zeroize` traits.
+
+use std::borrow::Cow;
+
+use ::zeroize::{Zeroize, Zeroizing};
+
+use crate::{Case, HexString};
+
+impl<const C: Case> Zeroize for HexString<C> {
+  /// Overwrites the owned storage of the [`HexString`] with zeroes and leaves it empty.
+  ///
+  /// A [`HexString`] borrowing a `'static` string is only emptied, since such a string lives in
+  /// read-only memory for the whole program anyway.
+  fn zeroize(&mut self) {
+    if let Cow::Owned(s) = &mut self.0 {
+      s.zeroize();
+    }
+
+    self.0 = Cow::Borrowed("");
+  }
+}
+
+impl<const C: Case> HexString<C> {
+  /// Wraps the [`HexString`] into [`Zeroizing`] so that it is wiped from memory when dropped.
+  ///
+  /// The wrapped [`HexString`] always owns its storage, a borrowed string being copied, so that
+  /// its value is actually overwritten on drop. Plain [`HexString`] values are not wiped on drop,
+  /// which keeps this cost to the values holding sensitive data.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use hexstring::LowerHexString;
+  ///
+  /// let key = LowerHexString::new("deadbeef").unwrap().into_zeroizing();
+  ///
+  /// assert_eq!(key.to_string(), "deadbeef");
+  /// ```
+  pub fn into_zeroizing(self) -> Zeroizing<Self> {
+    Zeroizing::new(Self(Cow::Owned(self.into_cow().into_owned())))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{LowerHexString, UpperHexString};
+
+  #[test]
+  fn it_zeroizes_owned_hex_str() {
+    let mut hex = LowerHexString::new(String::from("deadbeef")).unwrap();
+
+    hex.zeroize();
+
+    assert_eq!(hex, LowerHexString::default());
+  }
+
+  #[test]
+  fn it_zeroizes_borrowed_hex_str() {
+    let mut hex = UpperHexString::new("DEADBEEF").unwrap();
+
+    hex.zeroize();
+
+    assert_eq!(hex, UpperHexString::default());
+  }
+
+  #[test]
+  fn it_wraps_hex_str_into_owned_zeroizing() {
+    let key = UpperHexString::new("DEADBEEF").unwrap().into_zeroizing();
+
+    assert!(matches!(key.0, Cow::Owned(_)));
+    assert_eq!(*key, UpperHexString::new("DEADBEEF").unwrap());
+  }
+}